@@ -0,0 +1,34 @@
+//! Prints a day 17 program in readable form, one instruction per line.
+//!
+//! Usage: cargo run --example disassemble -- [path]
+//!
+//! The path defaults to the 2024 day 17 input.
+
+use std::fs;
+use std::process;
+
+use advent_of_code_2024::input;
+use advent_of_code_2024::solution::Solution;
+use advent_of_code_2024::year2024::day17::{self, Day17};
+
+fn main() {
+    let path = std::env::args()
+        .nth(1)
+        .map_or_else(|| input::day_path(2024, 17), Into::into);
+
+    let text = match fs::read_to_string(&path) {
+        Ok(text) => text,
+        Err(err) => {
+            eprintln!("Error: failed to read {}: {}", path.display(), err);
+            process::exit(1);
+        }
+    };
+
+    match Day17::parse(&text) {
+        Ok(computer) => print!("{}", day17::disassemble(&computer)),
+        Err(err) => {
+            eprintln!("Error: {}", err.render(&text, &path.display().to_string()));
+            process::exit(1);
+        }
+    }
+}
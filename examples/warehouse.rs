@@ -0,0 +1,55 @@
+//! Prints day 15's warehouse before and after every move, like the
+//! puzzle's walkthrough.
+//!
+//! Usage: cargo run --example warehouse -- [path] [--wide]
+//!
+//! The path defaults to the 2024 day 15 input. `--wide` replays the moves
+//! in part 2's widened warehouse.
+
+use std::fs;
+use std::path::PathBuf;
+use std::process;
+
+use advent_of_code_2024::input;
+use advent_of_code_2024::solution::Solution;
+use advent_of_code_2024::year2024::day15::{self, Day15};
+
+fn main() {
+    let mut path = None;
+    let mut wide = false;
+    for arg in std::env::args().skip(1) {
+        if arg == "--wide" {
+            wide = true;
+        } else if path.is_none() {
+            path = Some(PathBuf::from(arg));
+        } else {
+            eprintln!("Error: unexpected argument {}", arg);
+            process::exit(1);
+        }
+    }
+    let path = path.unwrap_or_else(|| input::day_path(2024, 15));
+
+    let text = match fs::read_to_string(&path) {
+        Ok(text) => text,
+        Err(err) => {
+            eprintln!("Error: failed to read {}: {}", path.display(), err);
+            process::exit(1);
+        }
+    };
+
+    let puzzle = match Day15::parse(&text) {
+        Ok(puzzle) => puzzle,
+        Err(err) => {
+            eprintln!("Error: {}", err.render(&text, &path.display().to_string()));
+            process::exit(1);
+        }
+    };
+
+    match day15::render_steps(&puzzle, wide) {
+        Ok(rendered) => print!("{}", rendered),
+        Err(message) => {
+            eprintln!("Error: {}", message);
+            process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,237 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::path::{Path, PathBuf};
+
+use crate::input;
+use crate::runner::DayResult;
+use crate::solution::Part;
+
+pub const FILE_NAME: &str = "answers.toml";
+
+/// The recorded answers for `year`, e.g. `inputs/2024/answers.toml`.
+pub fn path_for(year: u32) -> PathBuf {
+    input::year_dir(year).join(FILE_NAME)
+}
+
+/// Answers recorded for the checked-in inputs.
+///
+/// The file is a small TOML subset: a `[dayNN]` table per day holding
+/// `part1`/`part2` keys with quoted or bare values.
+#[derive(Debug, Default)]
+pub struct Answers {
+    recorded: HashMap<(u32, Part), String>,
+}
+
+impl Answers {
+    pub fn load(path: &Path) -> Result<Answers, String> {
+        let text = std::fs::read_to_string(path)
+            .map_err(|err| format!("Failed to read answers {}: {}", path.display(), err))?;
+
+        Answers::parse(&text).map_err(|err| format!("{}: {}", path.display(), err))
+    }
+
+    pub fn parse(text: &str) -> Result<Answers, String> {
+        let recorded = parse_tables(text, |header| {
+            header.strip_prefix("day")?.parse::<u32>().ok()
+        })?;
+
+        Ok(Answers { recorded })
+    }
+
+    pub fn get(&self, day: u32, part: Part) -> Option<&str> {
+        self.recorded.get(&(day, part)).map(String::as_str)
+    }
+}
+
+/// Parses an answers file whose table headers are turned into keys by
+/// `parse_header`. Each table holds `part1`/`part2` keys with quoted or bare
+/// values, and `#` starts a comment line.
+pub fn parse_tables<K: Eq + Hash + Copy>(
+    text: &str,
+    parse_header: impl Fn(&str) -> Option<K>,
+) -> Result<HashMap<(K, Part), String>, String> {
+    let mut recorded = HashMap::new();
+    let mut table = None;
+
+    for (index, line) in text.lines().enumerate() {
+        let line_number = index + 1;
+        let line = line.trim();
+
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        if let Some(header) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+            let parsed = parse_header(header.trim())
+                .ok_or_else(|| format!("line {}: invalid table [{}]", line_number, header))?;
+            table = Some(parsed);
+            continue;
+        }
+
+        let (key, value) = line
+            .split_once('=')
+            .ok_or_else(|| format!("line {}: expected key = value", line_number))?;
+        let table = table.ok_or_else(|| format!("line {}: key outside a table", line_number))?;
+        let part = match key.trim() {
+            "part1" => Part::One,
+            "part2" => Part::Two,
+            other => return Err(format!("line {}: unknown key {}", line_number, other)),
+        };
+
+        let value = value.trim();
+        let value = value
+            .strip_prefix('"')
+            .and_then(|v| v.strip_suffix('"'))
+            .unwrap_or(value);
+
+        recorded.insert((table, part), value.to_string());
+    }
+
+    Ok(recorded)
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Outcome {
+    Matched,
+    Mismatched { expected: String, actual: String },
+    Unrecorded,
+}
+
+pub struct Check {
+    pub day: u32,
+    pub part: Part,
+    pub outcome: Outcome,
+}
+
+/// Compares every part in `results` with its recorded answer.
+pub fn check(results: &[DayResult], answers: &Answers) -> Vec<Check> {
+    results
+        .iter()
+        .flat_map(|result| {
+            result.parts.iter().map(|part| {
+                let actual = part.answer.to_string();
+                let outcome = match answers.get(result.day, part.part) {
+                    None => Outcome::Unrecorded,
+                    Some(expected) if expected == actual => Outcome::Matched,
+                    Some(expected) => Outcome::Mismatched {
+                        expected: expected.to_string(),
+                        actual,
+                    },
+                };
+
+                Check {
+                    day: result.day,
+                    part: part.part,
+                    outcome,
+                }
+            })
+        })
+        .collect()
+}
+
+/// Renders mismatches as small diffs followed by a one-line summary.
+pub fn format_checks(checks: &[Check]) -> String {
+    let mut report = String::new();
+    let mut matched = 0;
+    let mut mismatched = 0;
+    let mut unrecorded = 0;
+
+    for check in checks {
+        match &check.outcome {
+            Outcome::Matched => matched += 1,
+            Outcome::Unrecorded => unrecorded += 1,
+            Outcome::Mismatched { expected, actual } => {
+                mismatched += 1;
+                report.push_str(&format!(
+                    "Day {} part {}: answer changed\n- {}\n+ {}\n",
+                    check.day, check.part, expected, actual
+                ));
+            }
+        }
+    }
+
+    report.push_str(&format!(
+        "Checked {} answers: {} matched, {} mismatched, {} unrecorded\n",
+        checks.len(),
+        matched,
+        mismatched,
+        unrecorded
+    ));
+
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runner::PartResult;
+    use crate::solution::Answer;
+    use std::time::Duration;
+
+    const ANSWERS: &str = "# Recorded answers
+[day01]
+part1 = \"11\"
+part2 = 31
+
+[day17]
+part1 = \"4,6,3,5,6,3,5,2,1,0\"
+";
+
+    fn result(day: u32, answers: &[u64]) -> DayResult {
+        DayResult {
+            day,
+            parse_elapsed: Duration::ZERO,
+            parts: Part::ALL
+                .iter()
+                .zip(answers)
+                .map(|(&part, &answer)| PartResult {
+                    part,
+                    answer: Answer::Number(answer),
+                    elapsed: Duration::ZERO,
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_parse() {
+        let answers = Answers::parse(ANSWERS).unwrap();
+
+        assert_eq!(answers.get(1, Part::One), Some("11"));
+        assert_eq!(answers.get(1, Part::Two), Some("31"));
+        assert_eq!(answers.get(17, Part::One), Some("4,6,3,5,6,3,5,2,1,0"));
+        assert_eq!(answers.get(17, Part::Two), None);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert!(Answers::parse("part1 = 1").is_err());
+        assert!(Answers::parse("[dayone]").is_err());
+        assert!(Answers::parse("[day01]\npart3 = 1").is_err());
+        assert!(Answers::parse("[day01]\npart1").is_err());
+    }
+
+    #[test]
+    fn test_check() {
+        let answers = Answers::parse(ANSWERS).unwrap();
+        let checks = check(&[result(1, &[11, 32]), result(2, &[2])], &answers);
+        let outcomes: Vec<&Outcome> = checks.iter().map(|check| &check.outcome).collect();
+
+        assert_eq!(
+            outcomes,
+            vec![
+                &Outcome::Matched,
+                &Outcome::Mismatched {
+                    expected: "31".to_string(),
+                    actual: "32".to_string()
+                },
+                &Outcome::Unrecorded,
+            ]
+        );
+        assert_eq!(
+            format_checks(&checks),
+            "Day 1 part 2: answer changed\n- 31\n+ 32\n\
+             Checked 3 answers: 1 matched, 1 mismatched, 1 unrecorded\n"
+        );
+    }
+}
@@ -0,0 +1,310 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::path::Path;
+use std::time::Duration;
+
+use crate::error::ParseResult;
+use crate::report::{align_columns, format_duration};
+use crate::runner;
+use crate::solution::{Part, Runnable, Settings};
+
+/// A median this much slower than its baseline is flagged as a slowdown.
+pub const SLOWDOWN_THRESHOLD: f64 = 1.10;
+
+/// A separately timed step of running a day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Stage {
+    Parse,
+    Part(Part),
+}
+
+impl Stage {
+    fn from_name(name: &str) -> Option<Stage> {
+        match name {
+            "parse" => Some(Stage::Parse),
+            "part1" => Some(Stage::Part(Part::One)),
+            "part2" => Some(Stage::Part(Part::Two)),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Stage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Stage::Parse => write!(f, "parse"),
+            Stage::Part(part) => write!(f, "part{}", part),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(mut samples: Vec<Duration>) -> Stats {
+        assert!(!samples.is_empty(), "Need at least one sample");
+        samples.sort();
+
+        let middle = samples.len() / 2;
+        let median = if samples.len().is_multiple_of(2) {
+            (samples[middle - 1] + samples[middle]) / 2
+        } else {
+            samples[middle]
+        };
+
+        Stats {
+            min: samples[0],
+            median,
+            max: samples[samples.len() - 1],
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Measurement {
+    pub day: u32,
+    pub stage: Stage,
+    pub stats: Stats,
+}
+
+/// Runs a day `iterations` times, parsing afresh each time, and summarises
+/// the timings of every stage.
+pub fn bench_day(
+    day: u32,
+    solution: &dyn Runnable,
+    input: &str,
+    settings: &Settings,
+    parts: &[Part],
+    iterations: usize,
+) -> ParseResult<Vec<Measurement>> {
+    let mut samples: Vec<(Stage, Vec<Duration>)> = std::iter::once(Stage::Parse)
+        .chain(parts.iter().map(|&part| Stage::Part(part)))
+        .map(|stage| (stage, Vec::with_capacity(iterations)))
+        .collect();
+
+    for _ in 0..iterations {
+        let result = runner::run_day(day, solution, input, settings, parts)?;
+
+        for (stage, durations) in samples.iter_mut() {
+            let elapsed = match stage {
+                Stage::Parse => result.parse_elapsed,
+                Stage::Part(part) => result.part(*part).unwrap().elapsed,
+            };
+            durations.push(elapsed);
+        }
+    }
+
+    Ok(samples
+        .into_iter()
+        .map(|(stage, durations)| Measurement {
+            day,
+            stage,
+            stats: Stats::from_samples(durations),
+        })
+        .collect())
+}
+
+/// Median timings from an earlier bench run, stored one `day stage nanos`
+/// entry per line.
+#[derive(Debug, Default)]
+pub struct Baseline {
+    medians: HashMap<(u32, Stage), Duration>,
+}
+
+impl Baseline {
+    pub fn from_measurements(measurements: &[Measurement]) -> Baseline {
+        Baseline {
+            medians: measurements
+                .iter()
+                .map(|measurement| {
+                    (
+                        (measurement.day, measurement.stage),
+                        measurement.stats.median,
+                    )
+                })
+                .collect(),
+        }
+    }
+
+    pub fn load(path: &Path) -> Result<Baseline, String> {
+        let text = std::fs::read_to_string(path)
+            .map_err(|err| format!("Failed to read baseline {}: {}", path.display(), err))?;
+
+        Baseline::parse(&text).map_err(|err| format!("{}: {}", path.display(), err))
+    }
+
+    pub fn parse(text: &str) -> Result<Baseline, String> {
+        let mut baseline = Baseline::default();
+
+        for (index, line) in text.lines().enumerate() {
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            let entry = match fields.as_slice() {
+                [day, stage, nanos] => day
+                    .parse::<u32>()
+                    .ok()
+                    .zip(Stage::from_name(stage).zip(nanos.parse::<u64>().ok())),
+                _ => None,
+            };
+            let (day, (stage, nanos)) = entry
+                .ok_or_else(|| format!("line {}: expected <day> <stage> <nanos>", index + 1))?;
+
+            baseline
+                .medians
+                .insert((day, stage), Duration::from_nanos(nanos));
+        }
+
+        Ok(baseline)
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        std::fs::write(path, self.to_text())
+            .map_err(|err| format!("Failed to write baseline {}: {}", path.display(), err))
+    }
+
+    fn to_text(&self) -> String {
+        let mut entries: Vec<_> = self.medians.iter().collect();
+        entries.sort_by_key(|((day, stage), _)| (*day, stage.to_string()));
+
+        entries
+            .into_iter()
+            .map(|((day, stage), median)| format!("{} {} {}\n", day, stage, median.as_nanos()))
+            .collect()
+    }
+
+    pub fn get(&self, day: u32, stage: Stage) -> Option<Duration> {
+        self.medians.get(&(day, stage)).copied()
+    }
+}
+
+/// True when `median` exceeds `baseline` by more than [`SLOWDOWN_THRESHOLD`].
+pub fn is_slowdown(median: Duration, baseline: Duration) -> bool {
+    median.as_secs_f64() > baseline.as_secs_f64() * SLOWDOWN_THRESHOLD
+}
+
+/// Renders measurements as a table, with a change column against `baseline`
+/// when one is given.
+pub fn format_bench(measurements: &[Measurement], baseline: Option<&Baseline>) -> String {
+    let mut header: Vec<String> = ["Day", "Stage", "Min", "Median", "Max"]
+        .map(String::from)
+        .to_vec();
+    if baseline.is_some() {
+        header.extend(["Baseline", "Change"].map(String::from));
+    }
+
+    let rows: Vec<Vec<String>> = measurements
+        .iter()
+        .map(|measurement| {
+            let stats = measurement.stats;
+            let mut row = vec![
+                measurement.day.to_string(),
+                measurement.stage.to_string(),
+                format_duration(stats.min),
+                format_duration(stats.median),
+                format_duration(stats.max),
+            ];
+
+            if let Some(baseline) = baseline {
+                match baseline.get(measurement.day, measurement.stage) {
+                    Some(previous) => {
+                        let change =
+                            (stats.median.as_secs_f64() / previous.as_secs_f64() - 1.0) * 100.0;
+                        let flag = if is_slowdown(stats.median, previous) {
+                            " SLOWER"
+                        } else {
+                            ""
+                        };
+                        row.push(format_duration(previous));
+                        row.push(format!("{:+.1}%{}", change, flag));
+                    }
+                    None => row.extend(["-", "-"].map(String::from)),
+                }
+            }
+
+            row
+        })
+        .collect();
+
+    align_columns(&header, &rows)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn measurement(day: u32, stage: Stage, median_micros: u64) -> Measurement {
+        let median = Duration::from_micros(median_micros);
+        Measurement {
+            day,
+            stage,
+            stats: Stats {
+                min: median,
+                median,
+                max: median,
+            },
+        }
+    }
+
+    #[test]
+    fn test_stats() {
+        let samples = [5, 1, 3, 9].map(Duration::from_millis).to_vec();
+
+        assert_eq!(
+            Stats::from_samples(samples),
+            Stats {
+                min: Duration::from_millis(1),
+                median: Duration::from_millis(4),
+                max: Duration::from_millis(9),
+            }
+        );
+        assert_eq!(
+            Stats::from_samples(vec![Duration::from_millis(2)]).median,
+            Duration::from_millis(2)
+        );
+    }
+
+    #[test]
+    fn test_baseline_round_trip() {
+        let measurements = vec![
+            measurement(1, Stage::Parse, 10),
+            measurement(1, Stage::Part(Part::Two), 250),
+        ];
+        let text = Baseline::from_measurements(&measurements).to_text();
+
+        assert_eq!(text, "1 parse 10000\n1 part2 250000\n");
+
+        let baseline = Baseline::parse(&text).unwrap();
+        assert_eq!(
+            baseline.get(1, Stage::Part(Part::Two)),
+            Some(Duration::from_micros(250))
+        );
+        assert_eq!(baseline.get(1, Stage::Part(Part::One)), None);
+        assert!(Baseline::parse("1 part3 10").is_err());
+    }
+
+    #[test]
+    fn test_format_bench_flags_slowdowns() {
+        let baseline = Baseline::from_measurements(&[
+            measurement(6, Stage::Part(Part::One), 100),
+            measurement(6, Stage::Part(Part::Two), 100),
+        ]);
+        let table = format_bench(
+            &[
+                measurement(6, Stage::Part(Part::One), 105),
+                measurement(6, Stage::Part(Part::Two), 150),
+            ],
+            Some(&baseline),
+        );
+
+        assert!(table.contains("+5.0%\n"));
+        assert!(table.contains("+50.0% SLOWER\n"));
+    }
+}
@@ -0,0 +1,501 @@
+use std::ops::RangeInclusive;
+use std::path::PathBuf;
+
+use crate::input::InputSource;
+use crate::registry;
+use crate::solution::{Part, Settings};
+
+pub const USAGE: &str = "\
+Usage: cargo run [bench] <day|first..last|all> [options]
+       cargo run new <day> [--year <year>]
+       cargo run watch <day> [--year <year>] [--part 1|2] [--input <path>] [--set ...] [--check]
+       cargo run submit <day> <part> [--year <year>] [--input <path>] [--set ...]
+
+Options:
+  --year <year>           Puzzle year, defaults to the latest registered one
+  --part 1|2              Only run one part
+  --input <path>|-        Read a single day's input from a file or stdin
+  --set <name>=<value>    Set something a single day's input leaves out, such as
+                          day 14's arena width and height. Can be repeated
+  --format text|json      Print a table (default) or one JSON object per day
+  --check                 Compare answers with inputs/<year>/answers.toml
+  --jobs <n>              Run solutions on n threads instead of one. Also runs
+                          days concurrently, so their times include contention
+
+Bench options:
+  --iterations <n>        Runs per day, defaults to 10
+  --save-baseline <path>  Record median timings for later comparison
+  --baseline <path>       Flag days that got slower than a saved baseline
+
+Environment:
+  AOC_SESSION             Session cookie used to download inputs and submit answers
+  AOC_URL                 Server to talk to, defaults to https://adventofcode.com";
+
+/// The subcommand to perform on the selected days.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Command {
+    Run,
+    Bench(BenchOptions),
+    /// Scaffold a new day module and its input files.
+    New,
+    /// Re-run a day and its examples whenever its source or input files
+    /// change, rebuilding first after a source change.
+    Watch,
+    /// Post one part's answer to the server, recording the attempt.
+    Submit,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BenchOptions {
+    pub iterations: usize,
+    pub save_baseline: Option<PathBuf>,
+    pub baseline: Option<PathBuf>,
+}
+
+impl Default for BenchOptions {
+    fn default() -> Self {
+        BenchOptions {
+            iterations: 10,
+            save_baseline: None,
+            baseline: None,
+        }
+    }
+}
+
+/// Which days to run.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Selection {
+    Day(u32),
+    /// An inclusive range, written `1..13` or `1..=13`.
+    Range(RangeInclusive<u32>),
+    All,
+}
+
+impl Selection {
+    /// The days of `year` covered by this selection, in ascending order.
+    /// Ranges only include registered days; a single day is returned as
+    /// asked for.
+    pub fn days(&self, year: u32) -> Vec<u32> {
+        if let Selection::Day(day) = self {
+            return vec![*day];
+        }
+
+        registry::DAYS
+            .iter()
+            .filter(|registered| registered.year == year)
+            .map(|registered| registered.day)
+            .filter(|day| match self {
+                Selection::Range(range) => range.contains(day),
+                _ => true,
+            })
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputFormat {
+    /// `Part 1: ...` lines for a single day, a summary table otherwise.
+    Text,
+    /// One JSON object per day and line.
+    Json,
+}
+
+/// What the runner was asked to do, as parsed from the command line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Options {
+    pub command: Command,
+    pub year: u32,
+    pub selection: Selection,
+    /// Only run this part, or both when `None`.
+    pub part: Option<Part>,
+    /// Overrides the day's default input; only valid for a single day.
+    pub input: Option<InputSource>,
+    pub format: OutputFormat,
+    /// Compare answers against the recorded answers file.
+    pub check: bool,
+    /// Number of worker threads, or `None` to run everything sequentially.
+    pub jobs: Option<usize>,
+    /// Puzzle settings from `--set`; only valid for a single day.
+    pub settings: Settings,
+}
+
+pub fn parse_args<I>(args: I) -> Result<Options, String>
+where
+    I: IntoIterator<Item = String>,
+{
+    let mut args = args.into_iter().peekable();
+    let mut command = Command::Run;
+    let mut year = None;
+    let mut selection = None;
+    let mut part = None;
+    let mut input = None;
+    let mut format = OutputFormat::Text;
+    let mut check = false;
+    let mut jobs = None;
+    let mut settings = Settings::default();
+
+    match args.peek().map(String::as_str) {
+        Some("bench") => command = Command::Bench(BenchOptions::default()),
+        Some("new") => command = Command::New,
+        Some("watch") => command = Command::Watch,
+        Some("submit") => command = Command::Submit,
+        _ => {}
+    }
+    if command != Command::Run {
+        args.next();
+    }
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--iterations" | "--save-baseline" | "--baseline" => {
+                let Command::Bench(bench) = &mut command else {
+                    return Err(format!("{} is only valid with bench", arg));
+                };
+                let value = args
+                    .next()
+                    .ok_or_else(|| format!("{} needs a value", arg))?;
+
+                match arg.as_str() {
+                    "--iterations" => bench.iterations = parse_iterations(&value)?,
+                    "--save-baseline" => bench.save_baseline = Some(PathBuf::from(value)),
+                    _ => bench.baseline = Some(PathBuf::from(value)),
+                }
+            }
+            "--year" => {
+                let value = args.next().ok_or("--year needs a value")?;
+                year = Some(parse_year(&value)?);
+            }
+            "--part" => {
+                let value = args.next().ok_or("--part needs a value")?;
+                part = Some(parse_part(&value)?);
+            }
+            "--input" => {
+                let value = args.next().ok_or("--input needs a path, or - for stdin")?;
+                input = Some(InputSource::from_arg(&value));
+            }
+            "--format" => {
+                let value = args.next().ok_or("--format needs a value")?;
+                format = parse_format(&value)?;
+            }
+            "--check" => check = true,
+            "--jobs" => {
+                let value = args.next().ok_or("--jobs needs a value")?;
+                jobs = Some(parse_jobs(&value)?);
+            }
+            "--set" => {
+                let value = args.next().ok_or("--set needs a name=value pair")?;
+                let (name, value) = parse_setting(&value)?;
+                settings.set(name, value);
+            }
+            _ if arg.starts_with("--") => return Err(format!("Unknown option {}", arg)),
+            _ if selection.is_none() => selection = Some(parse_selection(&arg)?),
+            _ if command == Command::Submit && part.is_none() => part = Some(parse_part(&arg)?),
+            _ => return Err(format!("Unexpected argument {}", arg)),
+        }
+    }
+
+    let selection = selection.ok_or("Missing day number")?;
+    let year = year
+        .or_else(registry::latest_year)
+        .ok_or("No days are registered yet, pass --year")?;
+
+    if command == Command::New && !matches!(selection, Selection::Day(_)) {
+        return Err("new takes a single day".to_string());
+    }
+
+    if command == Command::Watch {
+        if !matches!(selection, Selection::Day(_)) {
+            return Err("watch takes a single day".to_string());
+        }
+        if input == Some(InputSource::Stdin) {
+            return Err("watch cannot read its input from stdin".to_string());
+        }
+        if format == OutputFormat::Json {
+            return Err("watch only prints text".to_string());
+        }
+    }
+
+    if command == Command::Submit {
+        if !matches!(selection, Selection::Day(_)) || part.is_none() {
+            return Err("submit takes a single day and a part".to_string());
+        }
+        if format == OutputFormat::Json {
+            return Err("submit only prints text".to_string());
+        }
+    }
+
+    if input.is_some() && !matches!(selection, Selection::Day(_)) {
+        return Err("--input can only be used when running a single day".to_string());
+    }
+
+    if !settings.is_empty() && !matches!(selection, Selection::Day(_)) {
+        return Err("--set can only be used when running a single day".to_string());
+    }
+
+    Ok(Options {
+        command,
+        year,
+        selection,
+        part,
+        input,
+        format,
+        check,
+        jobs,
+        settings,
+    })
+}
+
+fn parse_selection(arg: &str) -> Result<Selection, String> {
+    if arg == "all" {
+        return Ok(Selection::All);
+    }
+
+    if let Some((first, last)) = arg.split_once("..") {
+        let last = last.strip_prefix('=').unwrap_or(last);
+        let (first, last) = (parse_day(first)?, parse_day(last)?);
+
+        if first > last {
+            return Err(format!("Empty day range {}", arg));
+        }
+
+        return Ok(Selection::Range(first..=last));
+    }
+
+    parse_day(arg).map(Selection::Day)
+}
+
+fn parse_day(arg: &str) -> Result<u32, String> {
+    match arg.parse::<u32>() {
+        Ok(day) if (1..=25).contains(&day) => Ok(day),
+        _ => Err("Provide a valid day number between 1 and 25.".to_string()),
+    }
+}
+
+fn parse_year(arg: &str) -> Result<u32, String> {
+    match arg.parse::<u32>() {
+        Ok(year) if year >= 2015 => Ok(year),
+        _ => Err(format!(
+            "Invalid year {}, Advent of Code started in 2015",
+            arg
+        )),
+    }
+}
+
+fn parse_jobs(arg: &str) -> Result<usize, String> {
+    match arg.parse::<usize>() {
+        Ok(jobs) if jobs > 0 => Ok(jobs),
+        _ => Err(format!("Invalid job count {}", arg)),
+    }
+}
+
+fn parse_iterations(arg: &str) -> Result<usize, String> {
+    match arg.parse::<usize>() {
+        Ok(iterations) if iterations > 0 => Ok(iterations),
+        _ => Err(format!("Invalid iteration count {}", arg)),
+    }
+}
+
+fn parse_format(arg: &str) -> Result<OutputFormat, String> {
+    match arg {
+        "text" => Ok(OutputFormat::Text),
+        "json" => Ok(OutputFormat::Json),
+        _ => Err(format!("Invalid format {}, expected text or json", arg)),
+    }
+}
+
+fn parse_setting(arg: &str) -> Result<(&str, u64), String> {
+    let parsed = arg
+        .split_once('=')
+        .map(|(name, value)| (name.trim(), value.trim().parse()));
+
+    match parsed {
+        Some((name, Ok(value))) if !name.is_empty() => Ok((name, value)),
+        _ => Err(format!(
+            "Invalid setting {}, expected a name and a number like width=101",
+            arg
+        )),
+    }
+}
+
+fn parse_part(arg: &str) -> Result<Part, String> {
+    match arg {
+        "1" => Ok(Part::One),
+        "2" => Ok(Part::Two),
+        _ => Err(format!("Invalid part {}, expected 1 or 2", arg)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn parse(args: &[&str]) -> Result<Options, String> {
+        parse_args(args.iter().map(|arg| arg.to_string()))
+    }
+
+    #[test]
+    fn test_day_only() {
+        let options = parse(&["7"]).unwrap();
+
+        assert_eq!(options.command, Command::Run);
+        assert_eq!(options.year, registry::latest_year().unwrap());
+        assert_eq!(options.selection, Selection::Day(7));
+        assert_eq!(options.part, None);
+        assert_eq!(options.input, None);
+        assert_eq!(options.format, OutputFormat::Text);
+        assert!(!options.check);
+        assert_eq!(options.jobs, None);
+        assert!(options.settings.is_empty());
+    }
+
+    #[test]
+    fn test_json_format_and_check() {
+        let options = parse(&["all", "--format", "json", "--check"]).unwrap();
+
+        assert_eq!(options.format, OutputFormat::Json);
+        assert!(options.check);
+    }
+
+    #[test]
+    fn test_jobs() {
+        assert_eq!(parse(&["all", "--jobs", "4"]).unwrap().jobs, Some(4));
+        assert!(parse(&["all", "--jobs", "0"]).is_err());
+        assert!(parse(&["all", "--jobs"]).is_err());
+    }
+
+    #[test]
+    fn test_part_and_input() {
+        let options = parse(&["--part", "2", "3", "--input", "example.txt"]).unwrap();
+
+        assert_eq!(options.selection, Selection::Day(3));
+        assert_eq!(options.part, Some(Part::Two));
+        assert_eq!(
+            options.input,
+            Some(InputSource::File(PathBuf::from("example.txt")))
+        );
+    }
+
+    #[test]
+    fn test_settings() {
+        let options = parse(&["14", "--set", "width=5", "--set", "height=3"]).unwrap();
+
+        assert_eq!(options.settings.get_or("width", 0u64), Ok(5));
+        assert_eq!(options.settings.get_or("height", 0u64), Ok(3));
+        assert!(parse(&["14", "--set", "width"]).is_err());
+        assert!(parse(&["14", "--set", "width=-1"]).is_err());
+        assert!(parse(&["14", "--set", "=5"]).is_err());
+        assert!(parse(&["all", "--set", "width=5"]).is_err());
+    }
+
+    #[test]
+    fn test_stdin_input() {
+        let options = parse(&["12", "--input", "-"]).unwrap();
+
+        assert_eq!(options.input, Some(InputSource::Stdin));
+    }
+
+    #[test]
+    fn test_selections() {
+        assert_eq!(parse(&["all"]).unwrap().selection, Selection::All);
+        assert_eq!(
+            parse(&["1..13"]).unwrap().selection,
+            Selection::Range(1..=13)
+        );
+        assert_eq!(
+            parse(&["3..=5"]).unwrap().selection,
+            Selection::Range(3..=5)
+        );
+        assert_eq!(Selection::Range(3..=5).days(2024), vec![3, 4, 5]);
+        assert_eq!(
+            Selection::All.days(2024).len(),
+            registry::DAYS
+                .iter()
+                .filter(|registered| registered.year == 2024)
+                .count()
+        );
+        assert!(Selection::All.days(2015).is_empty());
+    }
+
+    #[test]
+    fn test_year() {
+        let options = parse(&["all", "--year", "2023"]).unwrap();
+
+        assert_eq!(options.year, 2023);
+        assert_eq!(options.selection, Selection::All);
+        assert!(parse(&["1", "--year", "2014"]).is_err());
+        assert!(parse(&["1", "--year"]).is_err());
+    }
+
+    #[test]
+    fn test_bench() {
+        let options = parse(&[
+            "bench",
+            "all",
+            "--iterations",
+            "3",
+            "--baseline",
+            "base.txt",
+        ])
+        .unwrap();
+
+        assert_eq!(
+            options.command,
+            Command::Bench(BenchOptions {
+                iterations: 3,
+                save_baseline: None,
+                baseline: Some(PathBuf::from("base.txt")),
+            })
+        );
+        assert_eq!(options.selection, Selection::All);
+        assert!(parse(&["bench", "1", "--iterations", "0"]).is_err());
+        assert!(parse(&["1", "--iterations", "3"]).is_err());
+    }
+
+    #[test]
+    fn test_new() {
+        let options = parse(&["new", "14"]).unwrap();
+
+        assert_eq!(options.command, Command::New);
+        assert_eq!(options.selection, Selection::Day(14));
+        assert!(parse(&["new", "all"]).is_err());
+    }
+
+    #[test]
+    fn test_watch() {
+        let options = parse(&["watch", "6", "--part", "1", "--check"]).unwrap();
+
+        assert_eq!(options.command, Command::Watch);
+        assert_eq!(options.selection, Selection::Day(6));
+        assert!(parse(&["watch", "1..3"]).is_err());
+        assert!(parse(&["watch", "6", "--input", "-"]).is_err());
+        assert!(parse(&["watch", "6", "--format", "json"]).is_err());
+    }
+
+    #[test]
+    fn test_submit() {
+        let options = parse(&["submit", "4", "2", "--year", "2023"]).unwrap();
+
+        assert_eq!(options.command, Command::Submit);
+        assert_eq!(options.selection, Selection::Day(4));
+        assert_eq!(options.part, Some(Part::Two));
+        assert_eq!(options.year, 2023);
+        assert!(parse(&["submit", "4"]).is_err());
+        assert!(parse(&["submit", "1..4", "1"]).is_err());
+        assert!(parse(&["submit", "4", "3"]).is_err());
+    }
+
+    #[test]
+    fn test_invalid_arguments() {
+        assert!(parse(&[]).is_err());
+        assert!(parse(&["26"]).is_err());
+        assert!(parse(&["1", "--part", "3"]).is_err());
+        assert!(parse(&["1", "--input"]).is_err());
+        assert!(parse(&["1", "--verbose"]).is_err());
+        assert!(parse(&["1", "--format", "yaml"]).is_err());
+        assert!(parse(&["1", "2"]).is_err());
+        assert!(parse(&["5..2"]).is_err());
+        assert!(parse(&["0..30"]).is_err());
+        assert!(parse(&["all", "--input", "-"]).is_err());
+    }
+}
@@ -0,0 +1,112 @@
+use std::fmt;
+
+/// Why a puzzle input could not be parsed, and where. Lines and columns are
+/// 1-based and columns count characters rather than bytes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub message: String,
+}
+
+pub type ParseResult<T> = Result<T, ParseError>;
+
+impl ParseError {
+    pub fn new(line: usize, column: usize, message: impl Into<String>) -> ParseError {
+        ParseError {
+            line,
+            column,
+            message: message.into(),
+        }
+    }
+
+    /// An error for a whole line, given its 0-based index as produced by
+    /// `input.lines().enumerate()`.
+    pub fn at_line(line_index: usize, message: impl Into<String>) -> ParseError {
+        ParseError::new(line_index + 1, 1, message)
+    }
+
+    /// An error pointing at `token`, which must be a subslice of `line`.
+    pub fn at_token(
+        line_index: usize,
+        line: &str,
+        token: &str,
+        message: impl Into<String>,
+    ) -> ParseError {
+        let offset = (token.as_ptr() as usize).saturating_sub(line.as_ptr() as usize);
+        let column = line
+            .get(..offset)
+            .map_or(0, |prefix| prefix.chars().count());
+
+        ParseError::new(line_index + 1, column + 1, message)
+    }
+
+    /// Renders the error with the offending line of `input` and a caret under
+    /// the column, in the style of a compiler diagnostic.
+    pub fn render(&self, input: &str, source: &str) -> String {
+        let mut rendered = format!(
+            "{}\n --> {}:{}:{}\n",
+            self.message, source, self.line, self.column
+        );
+
+        if let Some(text) = input.lines().nth(self.line - 1) {
+            let number = self.line.to_string();
+            let gutter = " ".repeat(number.len());
+
+            rendered.push_str(&format!("{} |\n", gutter));
+            rendered.push_str(&format!("{} | {}\n", number, text));
+            rendered.push_str(&format!(
+                "{} | {}^\n",
+                gutter,
+                " ".repeat(self.column.saturating_sub(1))
+            ));
+        }
+
+        rendered
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.message
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_at_token() {
+        let line = "3   x4";
+        let token = line.split_whitespace().nth(1).unwrap();
+
+        assert_eq!(
+            ParseError::at_token(2, line, token, "invalid number"),
+            ParseError::new(3, 5, "invalid number")
+        );
+    }
+
+    #[test]
+    fn test_render() {
+        let input = "3   4\n4   3\n3   x4\n";
+        let error = ParseError::new(3, 5, "invalid number \"x4\"");
+
+        assert_eq!(
+            error.render(input, "inputs/day01.txt"),
+            "invalid number \"x4\"
+ --> inputs/day01.txt:3:5
+  |
+3 | 3   x4
+  |     ^
+"
+        );
+        assert_eq!(error.to_string(), "line 3, column 5: invalid number \"x4\"");
+    }
+}
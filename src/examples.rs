@@ -0,0 +1,168 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::answers::{self, Outcome};
+use crate::input;
+use crate::runner;
+use crate::solution::{Part, Runnable, Settings};
+
+/// The example inputs for `year`, e.g. `inputs/2024/examples`.
+pub fn dir_for(year: u32) -> PathBuf {
+    input::year_dir(year).join("examples")
+}
+
+/// Expected answers for the examples, in the same format as the year's
+/// `answers.toml` but with one `[dayNN-K]` table per example file.
+pub const ANSWERS_FILE: &str = answers::FILE_NAME;
+
+/// An example input `dayNN-K.txt` and the answers recorded for it.
+#[derive(Debug)]
+pub struct Example {
+    pub day: u32,
+    pub index: u32,
+    pub path: PathBuf,
+    pub expected: Vec<(Part, String)>,
+}
+
+impl Example {
+    pub fn name(&self) -> String {
+        example_name(self.day, self.index)
+    }
+
+    pub fn read_input(&self) -> Result<String, String> {
+        fs::read_to_string(&self.path)
+            .map_err(|err| format!("Failed to read {}: {}", self.path.display(), err))
+    }
+}
+
+fn example_name(day: u32, index: u32) -> String {
+    format!("day{:02}-{}", day, index)
+}
+
+/// Splits a name like `day01-2` into its day and example number.
+fn parse_name(name: &str) -> Option<(u32, u32)> {
+    let (day, index) = name.strip_prefix("day")?.split_once('-')?;
+
+    Some((day.parse().ok()?, index.parse().ok()?))
+}
+
+/// Finds every example in `dir`, sorted by day and example number. Examples
+/// without recorded answers are still listed, with nothing to check.
+pub fn discover(dir: &Path) -> Result<Vec<Example>, String> {
+    let answers_path = dir.join(ANSWERS_FILE);
+    let mut expected = match fs::read_to_string(&answers_path) {
+        Ok(text) => answers::parse_tables(&text, parse_name)
+            .map_err(|err| format!("{}: {}", answers_path.display(), err))?,
+        Err(_) => HashMap::new(),
+    };
+
+    let entries = fs::read_dir(dir)
+        .map_err(|err| format!("Failed to read examples {}: {}", dir.display(), err))?;
+
+    let mut examples = Vec::new();
+    for entry in entries {
+        let path = entry
+            .map_err(|err| format!("Failed to read examples {}: {}", dir.display(), err))?
+            .path();
+        let Some((day, index)) = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .and_then(|name| name.strip_suffix(".txt"))
+            .and_then(parse_name)
+        else {
+            continue;
+        };
+
+        let expected = Part::ALL
+            .iter()
+            .filter_map(|&part| Some((part, expected.remove(&((day, index), part))?)))
+            .collect();
+
+        examples.push(Example {
+            day,
+            index,
+            path,
+            expected,
+        });
+    }
+
+    if let Some((day, index)) = expected.keys().map(|(name, _)| *name).min() {
+        let name = example_name(day, index);
+        return Err(format!(
+            "{}: answers recorded for {} but {}.txt does not exist",
+            answers_path.display(),
+            name,
+            name,
+        ));
+    }
+
+    examples.sort_by_key(|example| (example.day, example.index));
+    Ok(examples)
+}
+
+/// Runs the parts of `example` that have recorded answers on `input`, which
+/// is usually [`Example::read_input`]. Examples always run with the default
+/// settings.
+pub fn check(
+    example: &Example,
+    solution: &dyn Runnable,
+    input: &str,
+) -> Result<Vec<(Part, Outcome)>, String> {
+    let parts: Vec<Part> = example.expected.iter().map(|(part, _)| *part).collect();
+
+    let result = runner::run_day(example.day, solution, input, &Settings::default(), &parts)
+        .map_err(|err| err.render(input, &example.path.display().to_string()))?;
+
+    Ok(example
+        .expected
+        .iter()
+        .zip(&result.parts)
+        .map(|((part, expected), result)| {
+            let actual = result.answer.to_string();
+            let outcome = if *expected == actual {
+                Outcome::Matched
+            } else {
+                Outcome::Mismatched {
+                    expected: expected.clone(),
+                    actual,
+                }
+            };
+            (*part, outcome)
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_name() {
+        assert_eq!(parse_name("day01-2"), Some((1, 2)));
+        assert_eq!(parse_name("day12-10"), Some((12, 10)));
+        assert_eq!(parse_name("day01"), None);
+        assert_eq!(parse_name("answers"), None);
+    }
+
+    #[test]
+    fn test_discover() {
+        let dir = std::env::temp_dir().join(format!("aoc-examples-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("day02-1.txt"), "").unwrap();
+        fs::write(dir.join("day01-1.txt"), "").unwrap();
+        fs::write(dir.join("notes.md"), "").unwrap();
+        fs::write(dir.join(ANSWERS_FILE), "[day01-1]\npart2 = 31\n").unwrap();
+
+        let examples = discover(&dir).unwrap();
+        let names: Vec<String> = examples.iter().map(Example::name).collect();
+        assert_eq!(names, ["day01-1", "day02-1"]);
+        assert_eq!(examples[0].expected, [(Part::Two, "31".to_string())]);
+        assert!(examples[1].expected.is_empty());
+
+        fs::write(dir.join(ANSWERS_FILE), "[day03-1]\npart1 = 1\n").unwrap();
+        assert!(discover(&dir).is_err());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,426 @@
+use std::fmt;
+use std::ops::{Add, Index, IndexMut, Mul, Neg, Sub};
+
+use crate::error::{ParseError, ParseResult};
+use crate::parsing;
+
+/// A cell coordinate. `x` grows to the right and `y` grows downwards, so the
+/// first line of the input is `y == 0`. Coordinates are signed so positions
+/// can step off the grid and be rejected by the bounds checks.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct Position {
+    pub x: i32,
+    pub y: i32,
+}
+
+impl Position {
+    pub const fn new(x: i32, y: i32) -> Position {
+        Position { x, y }
+    }
+
+    pub fn step(self, direction: Direction) -> Position {
+        self + direction.offset()
+    }
+
+    pub fn offset_by(self, direction: Direction, amount: i32) -> Position {
+        self + direction.offset() * amount
+    }
+
+    pub fn manhattan_distance(self, other: Position) -> u32 {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+}
+
+impl Add for Position {
+    type Output = Position;
+
+    fn add(self, other: Position) -> Position {
+        Position::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl Sub for Position {
+    type Output = Position;
+
+    fn sub(self, other: Position) -> Position {
+        Position::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl Neg for Position {
+    type Output = Position;
+
+    fn neg(self) -> Position {
+        Position::new(-self.x, -self.y)
+    }
+}
+
+impl Mul<i32> for Position {
+    type Output = Position;
+
+    fn mul(self, amount: i32) -> Position {
+        Position::new(self.x * amount, self.y * amount)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+    UpLeft,
+    UpRight,
+    DownLeft,
+    DownRight,
+}
+
+impl Direction {
+    /// The four directions that share an edge with a cell.
+    pub const CARDINAL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Down,
+        Direction::Left,
+        Direction::Right,
+    ];
+
+    pub const DIAGONAL: [Direction; 4] = [
+        Direction::UpLeft,
+        Direction::UpRight,
+        Direction::DownLeft,
+        Direction::DownRight,
+    ];
+
+    /// All eight directions, cardinal ones first.
+    pub const ALL: [Direction; 8] = [
+        Direction::Up,
+        Direction::Down,
+        Direction::Left,
+        Direction::Right,
+        Direction::UpLeft,
+        Direction::UpRight,
+        Direction::DownLeft,
+        Direction::DownRight,
+    ];
+
+    /// A distinct bit index per direction, for storing sets of directions in
+    /// a `u8`.
+    pub fn bit(self) -> u8 {
+        match self {
+            Direction::Up => 0,
+            Direction::Down => 1,
+            Direction::Left => 2,
+            Direction::Right => 3,
+            Direction::UpLeft => 4,
+            Direction::UpRight => 5,
+            Direction::DownLeft => 6,
+            Direction::DownRight => 7,
+        }
+    }
+
+    pub fn offset(self) -> Position {
+        match self {
+            Direction::Up => Position::new(0, -1),
+            Direction::Down => Position::new(0, 1),
+            Direction::Left => Position::new(-1, 0),
+            Direction::Right => Position::new(1, 0),
+            Direction::UpLeft => Position::new(-1, -1),
+            Direction::UpRight => Position::new(1, -1),
+            Direction::DownLeft => Position::new(-1, 1),
+            Direction::DownRight => Position::new(1, 1),
+        }
+    }
+
+    /// Rotates a quarter turn clockwise.
+    pub fn turn_right(self) -> Direction {
+        match self {
+            Direction::Up => Direction::Right,
+            Direction::Right => Direction::Down,
+            Direction::Down => Direction::Left,
+            Direction::Left => Direction::Up,
+            Direction::UpLeft => Direction::UpRight,
+            Direction::UpRight => Direction::DownRight,
+            Direction::DownRight => Direction::DownLeft,
+            Direction::DownLeft => Direction::UpLeft,
+        }
+    }
+
+    /// Rotates a quarter turn counter-clockwise.
+    pub fn turn_left(self) -> Direction {
+        self.reverse().turn_right()
+    }
+
+    pub fn reverse(self) -> Direction {
+        self.turn_right().turn_right()
+    }
+}
+
+/// Parses the arrows `^`, `v`, `<` and `>` used by several puzzles.
+impl TryFrom<char> for Direction {
+    type Error = &'static str;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '^' => Ok(Direction::Up),
+            'v' => Ok(Direction::Down),
+            '<' => Ok(Direction::Left),
+            '>' => Ok(Direction::Right),
+            _ => Err("Invalid direction, expected one of ^v<>"),
+        }
+    }
+}
+
+impl Direction {
+    /// The arrow for a cardinal direction, the inverse of
+    /// `Direction::try_from`. Diagonals have none.
+    pub fn arrow(self) -> Option<char> {
+        match self {
+            Direction::Up => Some('^'),
+            Direction::Down => Some('v'),
+            Direction::Left => Some('<'),
+            Direction::Right => Some('>'),
+            _ => None,
+        }
+    }
+}
+
+/// A rectangular grid stored row by row in a single `Vec`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Grid<T> {
+        Grid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    /// Parses one row per input line, converting each character with
+    /// `parse_cell`. Every row must have the same number of cells.
+    pub fn parse<F, E>(input: &str, mut parse_cell: F) -> ParseResult<Grid<T>>
+    where
+        F: FnMut(char) -> Result<T, E>,
+        E: Into<String>,
+    {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = Vec::new();
+
+        for (y, line) in parsing::lines(input) {
+            let row_start = cells.len();
+            for (x, character) in line.chars().enumerate() {
+                let cell = parse_cell(character)
+                    .map_err(|message| ParseError::new(y + 1, x + 1, message))?;
+                cells.push(cell);
+            }
+
+            let row_width = cells.len() - row_start;
+            match width {
+                None => width = Some(row_width),
+                Some(width) if width != row_width => {
+                    return Err(ParseError::new(
+                        y + 1,
+                        row_width.min(width) + 1,
+                        format!("Expected {} cells in every row, found {}", width, row_width),
+                    ));
+                }
+                Some(_) => {}
+            }
+            height += 1;
+        }
+
+        Ok(Grid {
+            width: width.unwrap_or(0),
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, position: Position) -> bool {
+        position.x >= 0
+            && position.y >= 0
+            && (position.x as usize) < self.width
+            && (position.y as usize) < self.height
+    }
+
+    fn index_of(&self, position: Position) -> Option<usize> {
+        self.contains(position)
+            .then(|| position.y as usize * self.width + position.x as usize)
+    }
+
+    pub fn get(&self, position: Position) -> Option<&T> {
+        self.index_of(position).map(|index| &self.cells[index])
+    }
+
+    pub fn get_mut(&mut self, position: Position) -> Option<&mut T> {
+        self.index_of(position).map(|index| &mut self.cells[index])
+    }
+
+    /// All positions in row-major order.
+    pub fn positions(&self) -> impl Iterator<Item = Position> {
+        let width = self.width;
+        (0..self.width * self.height)
+            .map(move |index| Position::new((index % width) as i32, (index / width) as i32))
+    }
+
+    /// All cells with their positions in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    /// A grid of the same shape with `f` applied to every cell.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    pub fn find(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<Position> {
+        self.iter()
+            .find(|(_, cell)| predicate(cell))
+            .map(|(position, _)| position)
+    }
+
+    /// The in-bounds neighbors sharing an edge with `position`.
+    pub fn neighbors4(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        self.neighbors_in(position, &Direction::CARDINAL)
+    }
+
+    /// The in-bounds neighbors sharing an edge or a corner with `position`.
+    pub fn neighbors8(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        self.neighbors_in(position, &Direction::ALL)
+    }
+
+    fn neighbors_in(
+        &self,
+        position: Position,
+        directions: &'static [Direction],
+    ) -> impl Iterator<Item = Position> + '_ {
+        directions
+            .iter()
+            .map(move |&direction| position.step(direction))
+            .filter(|&neighbor| self.contains(neighbor))
+    }
+}
+
+impl Grid<char> {
+    /// Parses a grid keeping every character as-is.
+    pub fn parse_chars(input: &str) -> ParseResult<Grid<char>> {
+        Grid::parse(input, Ok::<char, &str>)
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Position) -> &T {
+        self.get(position)
+            .unwrap_or_else(|| panic!("{:?} is outside the grid", position))
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, position: Position) -> &mut T {
+        self.get_mut(position)
+            .unwrap_or_else(|| panic!("{:?} is outside the grid", position))
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.cells.chunks(self.width.max(1)) {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEST_INPUT: &str = "ab.
+.c.";
+
+    #[test]
+    fn test_parse_and_display() {
+        let grid = Grid::parse_chars(TEST_INPUT).unwrap();
+
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[Position::new(1, 1)], 'c');
+        assert_eq!(grid.get(Position::new(3, 0)), None);
+        assert_eq!(grid.get(Position::new(0, -1)), None);
+        assert_eq!(grid.find(|&cell| cell == 'b'), Some(Position::new(1, 0)));
+        assert_eq!(grid.to_string(), "ab.\n.c.\n");
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = Grid::parse("12\n3x", |c| c.to_digit(10).ok_or("not a digit")).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 2));
+
+        let error = Grid::parse_chars("abc\nab").unwrap_err();
+        assert_eq!((error.line, error.column), (2, 3));
+
+        assert_eq!(Grid::parse_chars("").unwrap().width(), 0);
+    }
+
+    #[test]
+    fn test_parse_line_endings() {
+        let grid = Grid::parse_chars(TEST_INPUT).unwrap();
+
+        assert_eq!(Grid::parse_chars("ab.\r\n.c.\r\n").unwrap(), grid);
+        assert_eq!(Grid::parse_chars("ab.\n.c.\n\n\n").unwrap(), grid);
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let grid = Grid::new(3, 2, 0);
+
+        let corner: Vec<_> = grid.neighbors4(Position::new(0, 0)).collect();
+        assert_eq!(corner, [Position::new(0, 1), Position::new(1, 0)]);
+        assert_eq!(grid.neighbors8(Position::new(1, 0)).count(), 5);
+        assert_eq!(grid.positions().count(), 6);
+    }
+
+    #[test]
+    fn test_directions() {
+        assert_eq!(Direction::Up.turn_right(), Direction::Right);
+        assert_eq!(Direction::Up.turn_left(), Direction::Left);
+        assert_eq!(Direction::UpLeft.reverse(), Direction::DownRight);
+        assert_eq!(
+            Position::new(2, 2).offset_by(Direction::DownLeft, 2),
+            Position::new(0, 4)
+        );
+        assert_eq!(Direction::try_from('v'), Ok(Direction::Down));
+        for direction in Direction::CARDINAL {
+            assert_eq!(
+                Direction::try_from(direction.arrow().unwrap()),
+                Ok(direction)
+            );
+        }
+        assert_eq!(Direction::UpLeft.arrow(), None);
+    }
+}
@@ -0,0 +1,59 @@
+use std::fmt;
+use std::io::{self, Read};
+use std::path::PathBuf;
+
+/// Puzzle inputs, answers and examples live in one directory per year.
+pub const INPUTS_DIR: &str = "inputs";
+
+/// The directory holding a year's inputs, e.g. `inputs/2024`.
+pub fn year_dir(year: u32) -> PathBuf {
+    PathBuf::from(INPUTS_DIR).join(year.to_string())
+}
+
+/// Where the input for `day` of `year` is kept, e.g. `inputs/2024/day05.txt`.
+pub fn day_path(year: u32, day: u32) -> PathBuf {
+    year_dir(year).join(format!("day{:02}.txt", day))
+}
+
+/// Where a day's puzzle input is read from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    File(PathBuf),
+    Stdin,
+}
+
+impl InputSource {
+    /// The checked-in input for `day` of `year`.
+    pub fn for_day(year: u32, day: u32) -> InputSource {
+        InputSource::File(day_path(year, day))
+    }
+
+    /// Interprets a command-line path, where `-` means standard input.
+    pub fn from_arg(arg: &str) -> InputSource {
+        if arg == "-" {
+            InputSource::Stdin
+        } else {
+            InputSource::File(PathBuf::from(arg))
+        }
+    }
+
+    pub fn read(&self) -> io::Result<String> {
+        match self {
+            InputSource::File(path) => std::fs::read_to_string(path),
+            InputSource::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input)?;
+                Ok(input)
+            }
+        }
+    }
+}
+
+impl fmt::Display for InputSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputSource::File(path) => write!(f, "{}", path.display()),
+            InputSource::Stdin => write!(f, "<stdin>"),
+        }
+    }
+}
@@ -0,0 +1,28 @@
+//! Advent of Code solutions. Every `yearYYYY::dayNN` module implements
+//! [`Solution`], and [`registry::DAYS`] lists them by year and day for the
+//! runner.
+
+pub mod answers;
+pub mod bench;
+pub mod cli;
+pub mod error;
+pub mod examples;
+pub mod grid;
+pub mod input;
+pub mod parallel;
+pub mod parsing;
+pub mod provider;
+pub mod registry;
+pub mod report;
+pub mod runner;
+pub mod scaffold;
+pub mod search;
+pub mod solution;
+pub mod submit;
+pub mod watch;
+pub mod year2024;
+
+#[cfg(test)]
+mod mock_server;
+
+pub use solution::{Answer, Solution};
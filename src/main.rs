@@ -1,82 +1,441 @@
-mod day01;
-mod day02;
-mod day03;
-mod day04;
-mod day05;
-mod day06;
-mod day07;
-mod day08;
-mod day09;
-mod day10;
-mod day11;
-mod day12;
-mod day13;
-mod day14;
-mod day15;
-mod day16;
-mod day17;
-mod day18;
-mod day19;
-mod day20;
-mod day21;
-mod day22;
-mod day23;
-mod day24;
-mod day25;
-
-fn read_input(file: &str) -> String {
-    std::fs::read_to_string(file).expect("Failed to read input file")
-}
+use std::path::{Path, PathBuf};
+use std::process;
+use std::thread;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use advent_of_code_2024::answers::{self, Answers, Outcome};
+use advent_of_code_2024::bench::{self, Baseline};
+use advent_of_code_2024::cli::{self, BenchOptions, Command, Options, OutputFormat, Selection};
+use advent_of_code_2024::examples;
+use advent_of_code_2024::input::InputSource;
+use advent_of_code_2024::parallel;
+use advent_of_code_2024::provider::{self, HttpFetcher, InputProvider};
+use advent_of_code_2024::registry::{self, RegisteredDay};
+use advent_of_code_2024::report;
+use advent_of_code_2024::runner;
+use advent_of_code_2024::scaffold;
+use advent_of_code_2024::solution::{Answer, Part};
+use advent_of_code_2024::submit::{self, History, Verdict};
+use advent_of_code_2024::watch::{self, Watcher};
 
 fn main() {
-    let args: Vec<String> = std::env::args().collect();
-    if args.len() < 2 {
-        eprintln!("Usage: cargo run <day>");
-        return;
-    }
-
-    let day_arg = &args[1];
-
-    match day_arg.parse::<u32>() {
-        Ok(day) if (1..=25).contains(&day) => {
-            let filename = format!("inputs/day{:02}.txt", day);
-            let input = read_input(&filename);
-
-            match day {
-                1 => day01::run(input),
-                2 => day02::run(input),
-                3 => day03::run(input),
-                4 => day04::run(input),
-                5 => day05::run(input),
-                6 => day06::run(input),
-                7 => day07::run(input),
-                8 => day08::run(input),
-                9 => day09::run(input),
-                10 => day10::run(input),
-                11 => day11::run(input),
-                12 => day12::run(input),
-                13 => day13::run(input),
-                14 => day14::run(input),
-                15 => day15::run(input),
-                16 => day16::run(input),
-                17 => day17::run(input),
-                18 => day18::run(input),
-                19 => day19::run(input),
-                20 => day20::run(input),
-                21 => day21::run(input),
-                22 => day22::run(input),
-                23 => day23::run(input),
-                24 => day24::run(input),
-                25 => day25::run(input),
-                _ => {
-                    eprintln!("Day {} is not yet implemented.", day);
-                    std::process::exit(1);
+    let options = match cli::parse_args(std::env::args().skip(1)) {
+        Ok(options) => options,
+        Err(message) => {
+            eprintln!("Error: {}", message);
+            eprintln!("{}", cli::USAGE);
+            process::exit(1);
+        }
+    };
+
+    if let Some(jobs) = options.jobs {
+        parallel::set_jobs(jobs);
+    }
+
+    let succeeded = match &options.command {
+        Command::Run => run(&options),
+        Command::Bench(bench_options) => bench(&options, bench_options),
+        Command::New => new_day(&options),
+        Command::Watch => watch(&options),
+        Command::Submit => submit(&options),
+    };
+
+    if !succeeded {
+        process::exit(1);
+    }
+}
+
+fn run(options: &Options) -> bool {
+    let parts = selected_parts(options);
+    let mut results = Vec::new();
+    let mut succeeded = true;
+
+    // Inputs are loaded one at a time, so missing ones are downloaded one
+    // request after another. The days then run one at a time too, so the
+    // table times each day alone, unless --jobs asks for them to overlap.
+    let days = selected_days(options);
+    let loaded: Vec<_> = days.iter().map(|&day| load(day, options)).collect();
+    let jobs: Vec<runner::Job> = days
+        .iter()
+        .zip(&loaded)
+        .filter_map(|(&day, loaded)| {
+            let (registered, _, input) = loaded.as_ref().ok()?;
+            Some(runner::Job {
+                day,
+                solution: registered.solution,
+                input,
+                settings: &options.settings,
+            })
+        })
+        .collect();
+    let mut ran = runner::run_days(&jobs, &parts, options.jobs.is_some()).into_iter();
+
+    for loaded in &loaded {
+        let outcome = match loaded {
+            Ok((_, source, input)) => ran
+                .next()
+                .expect("every loaded day was run")
+                .map_err(|err| err.render(input, &source.to_string())),
+            Err(message) => Err(message.clone()),
+        };
+
+        match outcome {
+            Ok(result) => {
+                if options.format == OutputFormat::Json {
+                    println!("{}", report::format_json(&result));
                 }
+                results.push(result);
+            }
+            Err(message) => {
+                eprintln!("Error: {}", message);
+                succeeded = false;
             }
         }
-        _ => {
-            eprintln!("Error: Provide a valid day number between 1 and 25.");
-            std::process::exit(1);
+    }
+
+    if options.format == OutputFormat::Text {
+        if let (Selection::Day(_), [result]) = (&options.selection, results.as_slice()) {
+            for part in &result.parts {
+                println!("Part {}: {}", part.part, part.answer);
+            }
+        } else if !results.is_empty() {
+            print!("{}", report::format_table(&results));
         }
     }
+
+    if options.check && !check_answers(options.year, &results) {
+        succeeded = false;
+    }
+
+    succeeded
+}
+
+/// Compares `results` with the answers recorded for `year`, returning false
+/// on any mismatch.
+fn check_answers(year: u32, results: &[runner::DayResult]) -> bool {
+    match Answers::load(&answers::path_for(year)) {
+        Ok(recorded) => {
+            let checks = answers::check(results, &recorded);
+            eprint!("{}", answers::format_checks(&checks));
+
+            !checks
+                .iter()
+                .any(|check| matches!(check.outcome, Outcome::Mismatched { .. }))
+        }
+        Err(message) => {
+            eprintln!("Error: {}", message);
+            false
+        }
+    }
+}
+
+fn bench(options: &Options, bench_options: &BenchOptions) -> bool {
+    if cfg!(debug_assertions) {
+        eprintln!("Warning: benchmarking a debug build, run with --release for useful timings");
+    }
+
+    let baseline = match bench_options.baseline.as_deref().map(Baseline::load) {
+        Some(Ok(baseline)) => Some(baseline),
+        Some(Err(message)) => {
+            eprintln!("Error: {}", message);
+            return false;
+        }
+        None => None,
+    };
+
+    let parts = selected_parts(options);
+    let mut measurements = Vec::new();
+    let mut succeeded = true;
+
+    for day in selected_days(options) {
+        let outcome = load(day, options).and_then(|(registered, source, input)| {
+            bench::bench_day(
+                day,
+                registered.solution,
+                &input,
+                &options.settings,
+                &parts,
+                bench_options.iterations,
+            )
+            .map_err(|err| err.render(&input, &source.to_string()))
+        });
+
+        match outcome {
+            Ok(day_measurements) => measurements.extend(day_measurements),
+            Err(message) => {
+                eprintln!("Error: {}", message);
+                succeeded = false;
+            }
+        }
+    }
+
+    print!("{}", bench::format_bench(&measurements, baseline.as_ref()));
+
+    if let Some(path) = &bench_options.save_baseline {
+        if let Err(message) = Baseline::from_measurements(&measurements).save(path) {
+            eprintln!("Error: {}", message);
+            succeeded = false;
+        }
+    }
+
+    succeeded
+}
+
+fn new_day(options: &Options) -> bool {
+    let Selection::Day(day) = options.selection else {
+        unreachable!("new is only parsed with a single day");
+    };
+
+    match scaffold::create_day(Path::new("."), options.year, day) {
+        Ok(written) => {
+            for path in written {
+                println!("Wrote {}", path.display());
+            }
+            true
+        }
+        Err(message) => {
+            eprintln!("Error: {}", message);
+            false
+        }
+    }
+}
+
+fn watch(options: &Options) -> bool {
+    let Selection::Day(day) = options.selection else {
+        unreachable!("watch is only parsed with a single day");
+    };
+    let input_path = match options.input.clone() {
+        Some(InputSource::File(path)) => path,
+        Some(InputSource::Stdin) => unreachable!("watch is never parsed with stdin input"),
+        None => PathBuf::from(InputSource::for_day(options.year, day).to_string()),
+    };
+    let examples_dir = examples::dir_for(options.year);
+    let examples_dir = examples_dir.as_path();
+    let source_path = scaffold::module_path(options.year, day);
+    let mut watcher = Watcher::new();
+
+    println!(
+        "Watching {} day {} for changes, press Ctrl-C to stop",
+        options.year, day
+    );
+    watcher.poll(&watch::watched_paths(
+        options.year,
+        day,
+        Some(&input_path),
+        examples_dir,
+    ));
+    run_watched(day, options, examples_dir);
+
+    loop {
+        thread::sleep(watch::POLL_INTERVAL);
+
+        let paths = watch::watched_paths(options.year, day, Some(&input_path), examples_dir);
+        let changed = watcher.poll(&paths);
+
+        if changed.contains(&source_path) {
+            // The running binary still has the old code, so rebuild and
+            // start over. A failed build keeps watching for the next edit.
+            println!("\n== Changed: {}, rebuilding", source_path.display());
+            if watch::rebuild() {
+                eprintln!("Error: failed to restart: {}", watch::restart());
+                return false;
+            }
+        } else if !changed.is_empty() {
+            let names: Vec<String> = changed
+                .iter()
+                .map(|path| path.display().to_string())
+                .collect();
+            println!("\n== Changed: {}", names.join(", "));
+            run_watched(day, options, examples_dir);
+        }
+    }
+}
+
+/// One round of `watch`: the day's answers and timings, then its examples.
+fn run_watched(day: u32, options: &Options, examples_dir: &Path) {
+    let parts = selected_parts(options);
+    let outcome = load(day, options).and_then(|(registered, source, input)| {
+        runner::run_day(day, registered.solution, &input, &options.settings, &parts)
+            .map(|result| (registered, result))
+            .map_err(|err| err.render(&input, &source.to_string()))
+    });
+
+    let registered = match outcome {
+        Ok((registered, result)) => {
+            println!("Parse: {}", report::format_duration(result.parse_elapsed));
+            for part in &result.parts {
+                println!(
+                    "Part {}: {} ({})",
+                    part.part,
+                    part.answer,
+                    report::format_duration(part.elapsed)
+                );
+            }
+            if options.check {
+                check_answers(options.year, &[result]);
+            }
+            registered
+        }
+        Err(message) => {
+            eprintln!("Error: {}", message);
+            match registry::find(options.year, day) {
+                Some(registered) => registered,
+                None => return,
+            }
+        }
+    };
+
+    let found = match examples::discover(examples_dir) {
+        Ok(found) => found,
+        Err(message) => {
+            eprintln!("Error: {}", message);
+            return;
+        }
+    };
+
+    for example in found.iter().filter(|example| example.day == day) {
+        let outcomes = example
+            .read_input()
+            .and_then(|input| examples::check(example, registered.solution, &input));
+
+        match outcomes {
+            Ok(outcomes) => {
+                for (part, outcome) in outcomes {
+                    match outcome {
+                        Outcome::Mismatched { expected, actual } => println!(
+                            "Example {} part {}: expected {}, got {}",
+                            example.name(),
+                            part,
+                            expected,
+                            actual
+                        ),
+                        _ => println!("Example {} part {}: ok", example.name(), part),
+                    }
+                }
+            }
+            Err(message) => eprintln!("Error: {}", message),
+        }
+    }
+}
+
+fn submit(options: &Options) -> bool {
+    let (Selection::Day(day), Some(part)) = (&options.selection, options.part) else {
+        unreachable!("submit is only parsed with a single day and a part");
+    };
+
+    match submit_part(options, *day, part) {
+        Ok(verdict) => {
+            println!("Day {} part {}: {}", day, part, verdict);
+            verdict == Verdict::Correct
+        }
+        Err(message) => {
+            eprintln!("Error: {}", message);
+            false
+        }
+    }
+}
+
+fn submit_part(options: &Options, day: u32, part: Part) -> Result<Verdict, String> {
+    let client = HttpFetcher::from_env()
+        .ok_or_else(|| format!("Set {} to submit answers", provider::SESSION_VAR))?;
+    let mut history = History::load(&submit::history_path(options.year))?;
+
+    let (registered, source, input) = load(day, options)?;
+    let result = runner::run_day(day, registered.solution, &input, &options.settings, &[part])
+        .map_err(|err| err.render(&input, &source.to_string()))?;
+    let answer = &result.parts[0].answer;
+    match answer {
+        Answer::Unimplemented => {
+            return Err(format!("Day {} part {} is not implemented", day, part));
+        }
+        Answer::NoSolution(reason) => {
+            return Err(format!(
+                "Day {} part {} found no answer to submit: {}",
+                day, part, reason
+            ));
+        }
+        Answer::Number(_) | Answer::Text(_) => {}
+    }
+
+    let answer = answer.to_string();
+    println!("Answer: {}", answer);
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs());
+
+    submit::submit_answer(&client, &mut history, options.year, day, part, &answer, now)
+}
+
+/// The days to run. Ranges leave out days whose input is neither saved nor
+/// downloadable, with a note, so a freshly scaffolded day does not fail the
+/// whole run. A single day is always tried and reports a missing input as
+/// an error.
+fn selected_days(options: &Options) -> Vec<u32> {
+    let days = options.selection.days(options.year);
+    if let Selection::Day(_) = options.selection {
+        return days;
+    }
+
+    let provider = InputProvider::from_env();
+    days.into_iter()
+        .filter(|&day| {
+            let available = provider.is_available(options.year, day);
+            if !available {
+                eprintln!(
+                    "Skipping day {}: no input at {}",
+                    day,
+                    provider.path(options.year, day).display()
+                );
+            }
+            available
+        })
+        .collect()
+}
+
+fn selected_parts(options: &Options) -> Vec<Part> {
+    match options.part {
+        Some(part) => vec![part],
+        None => Part::ALL.to_vec(),
+    }
+}
+
+fn load(
+    day: u32,
+    options: &Options,
+) -> Result<(&'static RegisteredDay, InputSource, String), String> {
+    let registered = registry::find(options.year, day)
+        .ok_or_else(|| format!("Day {} of {} is not yet implemented.", day, options.year))?;
+
+    let known = registered.solution.settings();
+    let unknown = options.settings.unknown(known);
+    if !unknown.is_empty() {
+        return Err(match known {
+            [] => format!("Day {} takes no settings, got {}", day, unknown.join(", ")),
+            _ => format!(
+                "Day {} has no setting {}, expected one of {}",
+                day,
+                unknown.join(", "),
+                known.join(", ")
+            ),
+        });
+    }
+
+    let (source, input) = match &options.input {
+        Some(source) => {
+            let input = source
+                .read()
+                .map_err(|err| format!("Failed to read input {}: {}", source, err))?;
+            (source.clone(), input)
+        }
+        None => (
+            InputSource::for_day(options.year, day),
+            InputProvider::from_env().get(options.year, day)?,
+        ),
+    };
+
+    if input.trim().is_empty() {
+        return Err(format!("Input {} is empty", source));
+    }
+
+    Ok((registered, source, input))
 }
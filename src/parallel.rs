@@ -0,0 +1,122 @@
+//! Spreads independent work items over a fixed number of scoped threads.
+//!
+//! Everything runs on the calling thread unless `--jobs` asks for more, so
+//! timings and CPU use stay predictable by default. Workers take the next
+//! unclaimed item from a shared counter, so uneven items balance out. Work
+//! started from inside a worker runs sequentially, which keeps nested use (a
+//! parallel day inside parallel days) from multiplying the thread count.
+
+use std::cell::Cell;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+
+/// The configured number of threads.
+static JOBS: AtomicUsize = AtomicUsize::new(1);
+
+thread_local! {
+    static IN_WORKER: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Sets the number of threads used by [`map`], as given by `--jobs`. 1, the
+/// default, runs everything sequentially.
+pub fn set_jobs(jobs: usize) {
+    JOBS.store(jobs.max(1), Ordering::Relaxed);
+}
+
+pub fn jobs() -> usize {
+    JOBS.load(Ordering::Relaxed)
+}
+
+/// Applies `f` to every item, in parallel, returning the results in the
+/// order of `items`.
+pub fn map<T: Sync, R: Send>(items: &[T], f: impl Fn(&T) -> R + Sync) -> Vec<R> {
+    map_with(jobs(), items, f)
+}
+
+/// The number of items for which `predicate` holds, checked in parallel.
+pub fn count<T: Sync>(items: &[T], predicate: impl Fn(&T) -> bool + Sync) -> usize {
+    map(items, predicate).into_iter().filter(|&hit| hit).count()
+}
+
+fn map_with<T: Sync, R: Send>(jobs: usize, items: &[T], f: impl Fn(&T) -> R + Sync) -> Vec<R> {
+    let workers = jobs.min(items.len());
+    if workers <= 1 || IN_WORKER.get() {
+        return items.iter().map(f).collect();
+    }
+
+    let next = AtomicUsize::new(0);
+    let mut results: Vec<Option<R>> = items.iter().map(|_| None).collect();
+
+    thread::scope(|scope| {
+        let handles: Vec<_> = (0..workers)
+            .map(|_| {
+                scope.spawn(|| {
+                    IN_WORKER.set(true);
+                    let mut done = Vec::new();
+                    loop {
+                        let index = next.fetch_add(1, Ordering::Relaxed);
+                        let Some(item) = items.get(index) else {
+                            break;
+                        };
+                        done.push((index, f(item)));
+                    }
+                    done
+                })
+            })
+            .collect();
+
+        for handle in handles {
+            let done = handle
+                .join()
+                .unwrap_or_else(|panic| std::panic::resume_unwind(panic));
+            for (index, result) in done {
+                results[index] = Some(result);
+            }
+        }
+    });
+
+    results
+        .into_iter()
+        .map(|result| result.expect("every item is claimed by a worker"))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_map_keeps_order() {
+        let items: Vec<u64> = (0..1000).collect();
+        let squares: Vec<u64> = items.iter().map(|item| item * item).collect();
+
+        for jobs in [1, 2, 7, 2000] {
+            assert_eq!(map_with(jobs, &items, |item| item * item), squares);
+        }
+        assert!(map_with(4, &[] as &[u64], |item| *item).is_empty());
+    }
+
+    #[test]
+    fn test_nested_map_runs_sequentially() {
+        let outer: Vec<usize> = (0..8).collect();
+        let sums = map_with(4, &outer, |&size| {
+            assert!(IN_WORKER.get());
+            let inner: Vec<usize> = (0..size).collect();
+            map_with(4, &inner, |&item| item).into_iter().sum::<usize>()
+        });
+
+        assert_eq!(sums, [0, 0, 1, 3, 6, 10, 15, 21]);
+        assert!(!IN_WORKER.get());
+    }
+
+    #[test]
+    #[should_panic(expected = "item 3")]
+    fn test_panics_propagate() {
+        let items: Vec<u32> = (0..10).collect();
+        map_with(3, &items, |&item| {
+            if item == 3 {
+                panic!("item 3");
+            }
+        });
+    }
+}
@@ -0,0 +1,188 @@
+//! Helpers for the input shapes that most puzzles share. Every helper takes
+//! the 0-based index of the line it is reading so errors point at the input.
+//! Character grids are parsed with `grid::Grid::parse`.
+
+use std::str::FromStr;
+
+use crate::error::{ParseError, ParseResult};
+use crate::grid::Grid;
+
+/// Lines paired with their 0-based index. Trailing whitespace, including the
+/// `\r` of Windows line endings, is dropped, and so are blank lines at the
+/// end of the input.
+pub fn lines(input: &str) -> impl Iterator<Item = (usize, &str)> {
+    input.trim_end().lines().map(str::trim_end).enumerate()
+}
+
+/// Groups the input into blocks of non-blank lines separated by blank ones.
+pub fn blocks(input: &str) -> Vec<Vec<(usize, &str)>> {
+    let mut blocks = Vec::new();
+    let mut current = Vec::new();
+
+    for (line_index, line) in lines(input) {
+        if line.trim().is_empty() {
+            if !current.is_empty() {
+                blocks.push(std::mem::take(&mut current));
+            }
+        } else {
+            current.push((line_index, line));
+        }
+    }
+    if !current.is_empty() {
+        blocks.push(current);
+    }
+
+    blocks
+}
+
+/// Parses `token`, a slice of `line`, reporting its column on failure.
+pub fn number<T: FromStr>(line_index: usize, line: &str, token: &str) -> ParseResult<T> {
+    token.parse().map_err(|_| {
+        ParseError::at_token(
+            line_index,
+            line,
+            token,
+            format!("Invalid number {:?}", token),
+        )
+    })
+}
+
+/// Whitespace-separated numbers, such as `3   4`.
+pub fn numbers<T: FromStr>(line_index: usize, line: &str) -> ParseResult<Vec<T>> {
+    line.split_whitespace()
+        .map(|token| number(line_index, line, token))
+        .collect()
+}
+
+/// Numbers split on `separator`, such as `75,47,61`. Whitespace around each
+/// number is ignored.
+pub fn separated<T: FromStr>(
+    line_index: usize,
+    line: &str,
+    separator: char,
+) -> ParseResult<Vec<T>> {
+    line.split(separator)
+        .map(|token| number(line_index, line, token.trim()))
+        .collect()
+}
+
+/// Every integer embedded in arbitrary text, so `p=0,4 v=3,-3` gives
+/// `[0, 4, 3, -3]`. A `-` directly before a digit makes the number negative.
+pub fn integers<T: FromStr>(line_index: usize, line: &str) -> ParseResult<Vec<T>> {
+    let bytes = line.as_bytes();
+    let mut values = Vec::new();
+    let mut position = 0;
+
+    while position < bytes.len() {
+        let negative =
+            bytes[position] == b'-' && bytes.get(position + 1).is_some_and(u8::is_ascii_digit);
+        if !negative && !bytes[position].is_ascii_digit() {
+            position += 1;
+            continue;
+        }
+
+        let start = position;
+        position += 1;
+        while position < bytes.len() && bytes[position].is_ascii_digit() {
+            position += 1;
+        }
+        values.push(number(line_index, line, &line[start..position])?);
+    }
+
+    Ok(values)
+}
+
+/// Each character of `line` as a single digit, such as `2333133121414131402`.
+pub fn digits(line_index: usize, line: &str) -> ParseResult<Vec<u8>> {
+    line.chars()
+        .enumerate()
+        .map(|(x, character)| {
+            character
+                .to_digit(10)
+                .map(|digit| digit as u8)
+                .ok_or_else(|| ParseError::new(line_index + 1, x + 1, "Invalid digit"))
+        })
+        .collect()
+}
+
+/// A rectangular grid of single digits.
+pub fn digit_grid(input: &str) -> ParseResult<Grid<u8>> {
+    Grid::parse(input, |character| {
+        character
+            .to_digit(10)
+            .map(|digit| digit as u8)
+            .ok_or("Invalid digit")
+    })
+}
+
+/// Splits `line` around the first `separator`, trimming both halves, as in
+/// `Register A: 729`.
+pub fn key_value<'a>(
+    line_index: usize,
+    line: &'a str,
+    separator: &str,
+) -> ParseResult<(&'a str, &'a str)> {
+    let (key, value) = line.split_once(separator).ok_or_else(|| {
+        ParseError::at_line(line_index, format!("Expected key{}value", separator))
+    })?;
+
+    Ok((key.trim(), value.trim()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lines() {
+        let expected = [(0, "a"), (1, ""), (2, "b")];
+
+        assert!(lines("a\n\nb").eq(expected));
+        assert!(lines("a\r\n\r\nb\r\n").eq(expected));
+        assert!(lines("a \n\t\nb\n\n\n").eq(expected));
+        assert_eq!(lines("").count(), 0);
+    }
+
+    #[test]
+    fn test_blocks() {
+        let expected = [vec![(0, "a"), (1, "b")], vec![(4, "c")]];
+
+        assert_eq!(blocks("a\nb\n\n\nc\n"), expected);
+        assert_eq!(blocks("a\r\nb\r\n\r\n \r\nc\r\n\r\n"), expected);
+    }
+
+    #[test]
+    fn test_numbers() {
+        assert_eq!(numbers::<u32>(0, " 3   4 "), Ok(vec![3, 4]));
+        assert_eq!(separated::<u8>(0, "75, 47,61", ','), Ok(vec![75, 47, 61]));
+
+        let error = numbers::<u32>(2, "3 x4").unwrap_err();
+        assert_eq!((error.line, error.column), (3, 3));
+    }
+
+    #[test]
+    fn test_integers() {
+        assert_eq!(integers::<i64>(0, "p=0,4 v=3,-3"), Ok(vec![0, 4, 3, -3]));
+        assert_eq!(
+            integers::<i64>(0, "Button A: X+94, Y-34 - 5"),
+            Ok(vec![94, -34, 5])
+        );
+        assert!(integers::<u8>(0, "x=-1").is_err());
+    }
+
+    #[test]
+    fn test_digits() {
+        assert_eq!(digits(0, "2333"), Ok(vec![2, 3, 3, 3]));
+        assert_eq!(digits(0, "23a").unwrap_err().column, 3);
+        assert_eq!(digit_grid("12\n34").unwrap().width(), 2);
+    }
+
+    #[test]
+    fn test_key_value() {
+        assert_eq!(
+            key_value(0, "Register A: 729", ":"),
+            Ok(("Register A", "729"))
+        );
+        assert!(key_value(0, "Register A 729", ":").is_err());
+    }
+}
@@ -0,0 +1,242 @@
+//! Finds puzzle inputs, downloading and caching the ones that are missing.
+//!
+//! Inputs are cached in the same files the runner reads,
+//! `inputs/<year>/dayNN.txt`, so a downloaded input is only fetched once and
+//! can still be edited or replaced by hand. A missing or empty file counts as
+//! not yet cached.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use crate::input;
+
+/// The environment variable holding the `session` cookie of a logged-in
+/// adventofcode.com account.
+pub const SESSION_VAR: &str = "AOC_SESSION";
+
+/// The environment variable that overrides [`DEFAULT_URL`], for example to
+/// point at a mirror or a local test server.
+pub const URL_VAR: &str = "AOC_URL";
+
+pub const DEFAULT_URL: &str = "https://adventofcode.com";
+
+const USER_AGENT: &str = concat!(env!("CARGO_PKG_NAME"), "/", env!("CARGO_PKG_VERSION"));
+
+/// Downloads the puzzle input for a day.
+pub trait Fetcher {
+    fn fetch(&self, year: u32, day: u32) -> Result<String, String>;
+}
+
+/// Fetches inputs from adventofcode.com, or a server with the same routes.
+pub struct HttpFetcher {
+    base_url: String,
+    session: String,
+    agent: ureq::Agent,
+}
+
+impl HttpFetcher {
+    pub fn new(base_url: &str, session: &str) -> HttpFetcher {
+        HttpFetcher {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.trim().to_string(),
+            agent: ureq::AgentBuilder::new()
+                .timeout(Duration::from_secs(30))
+                .user_agent(USER_AGENT)
+                .build(),
+        }
+    }
+
+    /// Reads the session cookie from [`SESSION_VAR`] and the server from
+    /// [`URL_VAR`], falling back to [`DEFAULT_URL`]. Returns `None` when no
+    /// session is set.
+    pub fn from_env() -> Option<HttpFetcher> {
+        let session = std::env::var(SESSION_VAR).ok()?;
+        if session.trim().is_empty() {
+            return None;
+        }
+        let base_url = std::env::var(URL_VAR).unwrap_or_else(|_| DEFAULT_URL.to_string());
+
+        Some(HttpFetcher::new(&base_url, &session))
+    }
+
+    /// Sends a request to `path` with the session cookie attached.
+    pub fn request(&self, method: &str, path: &str) -> ureq::Request {
+        self.agent
+            .request(method, &format!("{}{}", self.base_url, path))
+            .set("Cookie", &format!("session={}", self.session))
+    }
+}
+
+impl Fetcher for HttpFetcher {
+    fn fetch(&self, year: u32, day: u32) -> Result<String, String> {
+        let path = format!("/{}/day/{}/input", year, day);
+        let url = format!("{}{}", self.base_url, path);
+
+        match self.request("GET", &path).call() {
+            Ok(response) => response
+                .into_string()
+                .map_err(|err| format!("Failed to read {}: {}", url, err)),
+            Err(ureq::Error::Status(status, _)) => {
+                let hint = match status {
+                    400 | 401 | 500 => format!(", check that {} is a valid session", SESSION_VAR),
+                    404 => " (not unlocked yet?)".to_string(),
+                    _ => String::new(),
+                };
+                Err(format!(
+                    "Failed to download {}: HTTP {}{}",
+                    url, status, hint
+                ))
+            }
+            Err(err) => Err(format!("Failed to download {}: {}", url, err)),
+        }
+    }
+}
+
+/// Resolves a day's input from the cache under `root`, downloading it with
+/// the fetcher when it is missing.
+pub struct InputProvider {
+    root: PathBuf,
+    fetcher: Option<Box<dyn Fetcher>>,
+}
+
+impl InputProvider {
+    pub fn new(root: &Path, fetcher: Option<Box<dyn Fetcher>>) -> InputProvider {
+        InputProvider {
+            root: root.to_path_buf(),
+            fetcher,
+        }
+    }
+
+    /// Caches in the working directory and downloads with
+    /// [`HttpFetcher::from_env`], if a session is set.
+    pub fn from_env() -> InputProvider {
+        let fetcher = HttpFetcher::from_env().map(|fetcher| Box::new(fetcher) as Box<dyn Fetcher>);
+        InputProvider::new(Path::new(""), fetcher)
+    }
+
+    pub fn path(&self, year: u32, day: u32) -> PathBuf {
+        self.root.join(input::day_path(year, day))
+    }
+
+    /// Whether [`get`](InputProvider::get) has an input to return without
+    /// anyone saving one first: it is cached, or it can be downloaded.
+    pub fn is_available(&self, year: u32, day: u32) -> bool {
+        self.fetcher.is_some() || self.cached(year, day).is_some()
+    }
+
+    fn cached(&self, year: u32, day: u32) -> Option<String> {
+        fs::read_to_string(self.path(year, day))
+            .ok()
+            .filter(|cached| !cached.trim().is_empty())
+    }
+
+    /// The input for `day` of `year`, downloaded and cached first if needed.
+    pub fn get(&self, year: u32, day: u32) -> Result<String, String> {
+        if let Some(cached) = self.cached(year, day) {
+            return Ok(cached);
+        }
+
+        let path = self.path(year, day);
+
+        let Some(fetcher) = &self.fetcher else {
+            return Err(format!(
+                "{} is missing or empty. Save your puzzle input there, or set {} to download it.",
+                path.display(),
+                SESSION_VAR
+            ));
+        };
+
+        let fetched = fetcher.fetch(year, day)?;
+        if fetched.trim().is_empty() {
+            return Err(format!(
+                "Downloaded an empty input for {} day {}",
+                year, day
+            ));
+        }
+
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|err| format!("Failed to create {}: {}", parent.display(), err))?;
+        }
+        fs::write(&path, &fetched)
+            .map_err(|err| format!("Failed to cache input in {}: {}", path.display(), err))?;
+
+        Ok(fetched)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock_server::MockServer;
+
+    fn temp_root(name: &str) -> PathBuf {
+        let root =
+            std::env::temp_dir().join(format!("aoc-provider-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        root
+    }
+
+    #[test]
+    fn test_http_fetcher() {
+        let server = MockServer::start(vec![(200, "1 2\n3 4\n"), (404, "Not found")]);
+        let fetcher = HttpFetcher::new(&format!("{}/", server.url), "secret\n");
+
+        assert_eq!(fetcher.fetch(2024, 1), Ok("1 2\n3 4\n".to_string()));
+        let error = fetcher.fetch(2024, 25).unwrap_err();
+        assert!(error.contains("HTTP 404"), "{}", error);
+
+        let requests = server.requests();
+        assert_eq!(requests[0].line, "GET /2024/day/1/input HTTP/1.1");
+        assert_eq!(requests[0].header("Cookie"), Some("session=secret"));
+        assert_eq!(requests[0].header("User-Agent"), Some(USER_AGENT));
+        assert_eq!(requests[1].line, "GET /2024/day/25/input HTTP/1.1");
+    }
+
+    #[test]
+    fn test_provider_downloads_and_caches() {
+        let root = temp_root("cache");
+        let server = MockServer::start(vec![(200, "3   4\n")]);
+        let fetcher = HttpFetcher::new(&server.url, "secret");
+        let provider = InputProvider::new(&root, Some(Box::new(fetcher)));
+
+        assert_eq!(provider.get(2024, 1), Ok("3   4\n".to_string()));
+        // The server only answers once, so this must come from the cache.
+        assert_eq!(provider.get(2024, 1), Ok("3   4\n".to_string()));
+        assert_eq!(
+            fs::read_to_string(root.join("inputs/2024/day01.txt")).unwrap(),
+            "3   4\n"
+        );
+        assert_eq!(server.requests().len(), 1);
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_provider_empty_inputs() {
+        let root = temp_root("empty");
+        let path = root.join("inputs/2024/day14.txt");
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, "\n").unwrap();
+
+        let offline = InputProvider::new(&root, None);
+        assert!(!offline.is_available(2024, 14));
+        let error = offline.get(2024, 14).unwrap_err();
+        assert!(error.contains("missing or empty"), "{}", error);
+
+        let server = MockServer::start(vec![(200, ""), (200, "p=0,4 v=3,-3\n")]);
+        let fetcher = HttpFetcher::new(&server.url, "secret");
+        let provider = InputProvider::new(&root, Some(Box::new(fetcher)));
+
+        let error = provider.get(2024, 14).unwrap_err();
+        assert!(error.contains("empty input"), "{}", error);
+        assert_eq!(fs::read_to_string(&path).unwrap(), "\n");
+        assert!(provider.is_available(2024, 14));
+        assert_eq!(provider.get(2024, 14), Ok("p=0,4 v=3,-3\n".to_string()));
+        assert!(offline.is_available(2024, 14));
+        server.requests();
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,57 @@
+use crate::solution::Runnable;
+
+pub struct RegisteredDay {
+    pub year: u32,
+    pub day: u32,
+    pub solution: &'static dyn Runnable,
+}
+
+impl RegisteredDay {
+    pub const fn new(year: u32, day: u32, solution: &'static dyn Runnable) -> RegisteredDay {
+        RegisteredDay {
+            year,
+            day,
+            solution,
+        }
+    }
+}
+
+/// Every day the runner knows about, ordered by year and then day.
+/// `cargo run new <day> --year <year>` adds entries here.
+pub const DAYS: &[RegisteredDay] = &[
+    RegisteredDay::new(2024, 1, &crate::year2024::day01::Day01),
+    RegisteredDay::new(2024, 2, &crate::year2024::day02::Day02),
+    RegisteredDay::new(2024, 3, &crate::year2024::day03::Day03),
+    RegisteredDay::new(2024, 4, &crate::year2024::day04::Day04),
+    RegisteredDay::new(2024, 5, &crate::year2024::day05::Day05),
+    RegisteredDay::new(2024, 6, &crate::year2024::day06::Day06),
+    RegisteredDay::new(2024, 7, &crate::year2024::day07::Day07),
+    RegisteredDay::new(2024, 8, &crate::year2024::day08::Day08),
+    RegisteredDay::new(2024, 9, &crate::year2024::day09::Day09),
+    RegisteredDay::new(2024, 10, &crate::year2024::day10::Day10),
+    RegisteredDay::new(2024, 11, &crate::year2024::day11::Day11),
+    RegisteredDay::new(2024, 12, &crate::year2024::day12::Day12),
+    RegisteredDay::new(2024, 13, &crate::year2024::day13::Day13),
+    RegisteredDay::new(2024, 14, &crate::year2024::day14::Day14),
+    RegisteredDay::new(2024, 15, &crate::year2024::day15::Day15),
+    RegisteredDay::new(2024, 16, &crate::year2024::day16::Day16),
+    RegisteredDay::new(2024, 17, &crate::year2024::day17::Day17),
+    RegisteredDay::new(2024, 18, &crate::year2024::day18::Day18),
+];
+
+pub fn find(year: u32, day: u32) -> Option<&'static RegisteredDay> {
+    DAYS.iter()
+        .find(|registered| registered.year == year && registered.day == day)
+}
+
+/// The years with at least one registered day, in ascending order.
+pub fn years() -> Vec<u32> {
+    let mut years: Vec<u32> = DAYS.iter().map(|registered| registered.year).collect();
+    years.dedup();
+    years
+}
+
+/// The year the runner uses when `--year` is not given.
+pub fn latest_year() -> Option<u32> {
+    DAYS.iter().map(|registered| registered.year).max()
+}
@@ -0,0 +1,257 @@
+use std::time::Duration;
+
+use crate::runner::DayResult;
+use crate::solution::{Answer, Part};
+
+/// Formats `duration` with a unit that keeps a few significant digits.
+pub fn format_duration(duration: Duration) -> String {
+    let nanos = duration.as_nanos();
+
+    if nanos < 1_000 {
+        format!("{}ns", nanos)
+    } else if nanos < 1_000_000 {
+        format!("{:.1}µs", nanos as f64 / 1e3)
+    } else if nanos < 1_000_000_000 {
+        format!("{:.2}ms", nanos as f64 / 1e6)
+    } else {
+        format!("{:.2}s", nanos as f64 / 1e9)
+    }
+}
+
+/// Renders an aligned summary table with one row per day, a total row and a
+/// count of implemented versus pending parts.
+pub fn format_table(results: &[DayResult]) -> String {
+    let header = ["Day", "Part 1", "Time", "Part 2", "Time", "Parse", "Total"].map(String::from);
+
+    let mut rows: Vec<[String; 7]> = results
+        .iter()
+        .map(|result| {
+            let answer = |part| {
+                result
+                    .part(part)
+                    .map_or("-".to_string(), |part| part.answer.to_string())
+            };
+            let elapsed = |part| {
+                result
+                    .part(part)
+                    .map_or("-".to_string(), |part| format_duration(part.elapsed))
+            };
+
+            [
+                result.day.to_string(),
+                answer(Part::One),
+                elapsed(Part::One),
+                answer(Part::Two),
+                elapsed(Part::Two),
+                format_duration(result.parse_elapsed),
+                format_duration(result.total_elapsed()),
+            ]
+        })
+        .collect();
+
+    let total: Duration = results.iter().map(DayResult::total_elapsed).sum();
+    let mut total_row: [String; 7] = Default::default();
+    total_row[0] = "Total".to_string();
+    total_row[6] = format_duration(total);
+    rows.push(total_row);
+
+    let (implemented, pending) = results
+        .iter()
+        .map(DayResult::implemented_counts)
+        .fold((0, 0), |(implemented, pending), (i, p)| {
+            (implemented + i, pending + p)
+        });
+
+    format!(
+        "{}{} parts implemented, {} pending\n",
+        align_columns(&header, &rows),
+        implemented,
+        pending
+    )
+}
+
+/// Lays out `rows` under `header` with two spaces between columns. The first
+/// column is left-aligned and the rest right-aligned.
+pub fn align_columns<R: AsRef<[String]>>(header: &[String], rows: &[R]) -> String {
+    let mut widths: Vec<usize> = header
+        .iter()
+        .map(|heading| heading.chars().count())
+        .collect();
+    for row in rows {
+        for (width, cell) in widths.iter_mut().zip(row.as_ref()) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let separator: Vec<String> = widths.iter().map(|&width| "-".repeat(width)).collect();
+    let mut table = String::new();
+
+    for row in [header, separator.as_slice()]
+        .into_iter()
+        .chain(rows.iter().map(AsRef::as_ref))
+    {
+        let line: Vec<String> = row
+            .iter()
+            .zip(&widths)
+            .enumerate()
+            .map(|(column, (cell, &width))| {
+                let padding = " ".repeat(width - cell.chars().count());
+                if column == 0 {
+                    format!("{}{}", cell, padding)
+                } else {
+                    format!("{}{}", padding, cell)
+                }
+            })
+            .collect();
+        table.push_str(line.join("  ").trim_end());
+        table.push('\n');
+    }
+
+    table
+}
+
+/// Renders one day as a single-line JSON object. Answers are always strings
+/// so large numbers survive consumers that parse JSON numbers as doubles, or
+/// `null` for parts that are not implemented or have no solution.
+pub fn format_json(result: &DayResult) -> String {
+    let parts: Vec<String> = result
+        .parts
+        .iter()
+        .map(|part| {
+            let answer = match part.answer {
+                Answer::Unimplemented | Answer::NoSolution(_) => "null".to_string(),
+                ref answer => json_string(&answer.to_string()),
+            };
+
+            format!(
+                "{{\"part\":{},\"answer\":{},\"elapsed_ns\":{}}}",
+                part.part,
+                answer,
+                part.elapsed.as_nanos()
+            )
+        })
+        .collect();
+
+    format!(
+        "{{\"day\":{},\"parse_elapsed_ns\":{},\"parts\":[{}]}}",
+        result.day,
+        result.parse_elapsed.as_nanos(),
+        parts.join(",")
+    )
+}
+
+fn json_string(value: &str) -> String {
+    let mut escaped = String::with_capacity(value.len() + 2);
+    escaped.push('"');
+
+    for character in value.chars() {
+        match character {
+            '"' => escaped.push_str("\\\""),
+            '\\' => escaped.push_str("\\\\"),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => escaped.push_str("\\r"),
+            '\t' => escaped.push_str("\\t"),
+            c if (c as u32) < 0x20 => escaped.push_str(&format!("\\u{:04x}", c as u32)),
+            c => escaped.push(c),
+        }
+    }
+
+    escaped.push('"');
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::runner::PartResult;
+
+    #[test]
+    fn test_format_duration() {
+        assert_eq!(format_duration(Duration::from_nanos(512)), "512ns");
+        assert_eq!(format_duration(Duration::from_micros(42)), "42.0µs");
+        assert_eq!(format_duration(Duration::from_micros(1_500)), "1.50ms");
+        assert_eq!(format_duration(Duration::from_millis(2_250)), "2.25s");
+    }
+
+    #[test]
+    fn test_format_json() {
+        let result = DayResult {
+            day: 17,
+            parse_elapsed: Duration::from_nanos(250),
+            parts: vec![
+                PartResult {
+                    part: Part::One,
+                    answer: Answer::Text("4,6,3".to_string()),
+                    elapsed: Duration::from_micros(3),
+                },
+                PartResult {
+                    part: Part::Two,
+                    answer: Answer::Unimplemented,
+                    elapsed: Duration::from_nanos(40),
+                },
+            ],
+        };
+
+        assert_eq!(
+            format_json(&result),
+            r#"{"day":17,"parse_elapsed_ns":250,"parts":[{"part":1,"answer":"4,6,3","elapsed_ns":3000},{"part":2,"answer":null,"elapsed_ns":40}]}"#
+        );
+    }
+
+    #[test]
+    fn test_json_string() {
+        assert_eq!(json_string("plain"), r#""plain""#);
+        assert_eq!(json_string("a\"b\\c\nd"), r#""a\"b\\c\nd""#);
+        assert_eq!(json_string("\u{1}"), r#""\u0001""#);
+    }
+
+    #[test]
+    fn test_format_table() {
+        let results = vec![
+            DayResult {
+                day: 1,
+                parse_elapsed: Duration::from_micros(10),
+                parts: vec![
+                    PartResult {
+                        part: Part::One,
+                        answer: Answer::Number(11),
+                        elapsed: Duration::from_micros(5),
+                    },
+                    PartResult {
+                        part: Part::Two,
+                        answer: Answer::Number(31),
+                        elapsed: Duration::from_micros(5),
+                    },
+                ],
+            },
+            DayResult {
+                day: 11,
+                parse_elapsed: Duration::from_micros(10),
+                parts: vec![
+                    PartResult {
+                        part: Part::One,
+                        answer: Answer::Number(55312),
+                        elapsed: Duration::from_micros(80),
+                    },
+                    PartResult {
+                        part: Part::Two,
+                        answer: Answer::Unimplemented,
+                        elapsed: Duration::ZERO,
+                    },
+                ],
+            },
+        ];
+
+        assert_eq!(
+            format_table(&results),
+            "\
+Day    Part 1    Time           Part 2   Time   Parse    Total
+-----  ------  ------  ---------------  -----  ------  -------
+1          11   5.0µs               31  5.0µs  10.0µs   20.0µs
+11      55312  80.0µs  not implemented    0ns  10.0µs   90.0µs
+Total                                                  110.0µs
+3 parts implemented, 1 pending
+"
+        );
+    }
+}
@@ -0,0 +1,102 @@
+use std::time::{Duration, Instant};
+
+use crate::error::ParseResult;
+use crate::parallel;
+use crate::solution::{Answer, Part, Runnable, Settings};
+
+pub struct PartResult {
+    pub part: Part,
+    pub answer: Answer,
+    pub elapsed: Duration,
+}
+
+/// Answers and wall-clock timings from running one day.
+pub struct DayResult {
+    pub day: u32,
+    pub parse_elapsed: Duration,
+    pub parts: Vec<PartResult>,
+}
+
+impl DayResult {
+    pub fn part(&self, part: Part) -> Option<&PartResult> {
+        self.parts.iter().find(|result| result.part == part)
+    }
+
+    /// Number of parts that produced a real answer, and number still pending.
+    pub fn implemented_counts(&self) -> (usize, usize) {
+        let implemented = self
+            .parts
+            .iter()
+            .filter(|result| result.answer.is_implemented())
+            .count();
+
+        (implemented, self.parts.len() - implemented)
+    }
+
+    pub fn total_elapsed(&self) -> Duration {
+        self.parse_elapsed
+            + self
+                .parts
+                .iter()
+                .map(|result| result.elapsed)
+                .sum::<Duration>()
+    }
+}
+
+pub fn run_day(
+    day: u32,
+    solution: &dyn Runnable,
+    input: &str,
+    settings: &Settings,
+    parts: &[Part],
+) -> ParseResult<DayResult> {
+    let (parsed, parse_elapsed) = timed(|| solution.parse(input, settings));
+    let parsed = parsed?;
+
+    let parts = parts
+        .iter()
+        .map(|&part| {
+            let (answer, elapsed) = timed(|| parsed.solve(part));
+            PartResult {
+                part,
+                answer,
+                elapsed,
+            }
+        })
+        .collect();
+
+    Ok(DayResult {
+        day,
+        parse_elapsed,
+        parts,
+    })
+}
+
+/// A day's solution together with the input to run it on.
+pub struct Job<'a> {
+    pub day: u32,
+    pub solution: &'a dyn Runnable,
+    pub input: &'a str,
+    pub settings: &'a Settings,
+}
+
+/// Runs several days, returning the results in the order of `jobs`.
+///
+/// With `concurrent` the days share the [`parallel`] pool. Timings are
+/// wall-clock, so they then include contention between the days rather than
+/// measuring each day alone; otherwise the days run one after another.
+pub fn run_days(jobs: &[Job], parts: &[Part], concurrent: bool) -> Vec<ParseResult<DayResult>> {
+    let run = |job: &Job| run_day(job.day, job.solution, job.input, job.settings, parts);
+
+    if concurrent {
+        parallel::map(jobs, run)
+    } else {
+        jobs.iter().map(run).collect()
+    }
+}
+
+fn timed<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+    let start = Instant::now();
+    let value = f();
+    (value, start.elapsed())
+}
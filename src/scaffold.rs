@@ -0,0 +1,265 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use regex::Regex;
+
+use crate::examples;
+use crate::input;
+
+const TEMPLATE: &str = r#"use crate::error::ParseResult;
+use crate::solution::{Answer, Solution};
+
+pub struct Day__DAY__;
+
+impl Solution for Day__DAY__ {
+    type Input = String;
+
+    fn parse(input: &str) -> ParseResult<String> {
+        Ok(input.to_string())
+    }
+
+    fn part1(_input: &String) -> Answer {
+        Answer::Unimplemented
+    }
+
+    fn part2(_input: &String) -> Answer {
+        Answer::Unimplemented
+    }
+}
+"#;
+
+/// Where a day's solution module lives, relative to the crate root.
+pub fn module_path(year: u32, day: u32) -> PathBuf {
+    PathBuf::from(format!("src/year{}/day{:02}.rs", year, day))
+}
+
+/// Generates the module for `day` of `year` under the crate at `root`,
+/// declares it in the year's module (creating that on a year's first day),
+/// registers it, creates empty input and example files and adds a table for
+/// the example's answers. Returns every path that was written.
+pub fn create_day(root: &Path, year: u32, day: u32) -> Result<Vec<PathBuf>, String> {
+    let padded = format!("{:02}", day);
+    let module_path = root.join(module_path(year, day));
+
+    if module_path.exists() {
+        return Err(format!("{} already exists", module_path.display()));
+    }
+
+    let module_line = format!("pub mod day{};", padded);
+    let year_path = root.join(format!("src/year{}.rs", year));
+    let lib_path = root.join("src/lib.rs");
+    let mut updates = Vec::new();
+
+    if year_path.exists() {
+        let year_module = insert_sorted(
+            &read(&year_path)?,
+            &module_line,
+            r"^pub mod day(\d+);$",
+            &[day],
+        )
+        .ok_or_else(|| format!("No day modules declared in {}", year_path.display()))?;
+        updates.push((year_path, year_module));
+    } else {
+        let lib = insert_sorted(
+            &read(&lib_path)?,
+            &format!("pub mod year{};", year),
+            r"^pub mod year(\d+);$",
+            &[year],
+        )
+        .ok_or_else(|| format!("No year modules declared in {}", lib_path.display()))?;
+        updates.push((
+            year_path,
+            format!("//! Advent of Code {}.\n\n{}\n", year, module_line),
+        ));
+        updates.push((lib_path, lib));
+    }
+
+    let registry_path = root.join("src/registry.rs");
+    let registry = insert_sorted(
+        &read(&registry_path)?,
+        &format!(
+            "    RegisteredDay::new({}, {}, &crate::year{}::day{}::Day{}),",
+            year, day, year, padded, padded
+        ),
+        r"^\s*RegisteredDay::new\((\d+), (\d+),",
+        &[year, day],
+    )
+    .ok_or_else(|| format!("No registered days in {}", registry_path.display()))?;
+    updates.push((registry_path, registry));
+
+    create_parent(&module_path)?;
+    write(&module_path, &TEMPLATE.replace("__DAY__", &padded))?;
+    let mut written = vec![module_path];
+    for (path, contents) in updates {
+        write(&path, &contents)?;
+        written.push(path);
+    }
+
+    let examples_dir = root.join(examples::dir_for(year));
+    for path in [
+        root.join(input::year_dir(year))
+            .join(format!("day{}.txt", padded)),
+        examples_dir.join(format!("day{}-1.txt", padded)),
+    ] {
+        if !path.exists() {
+            create_parent(&path)?;
+            write(&path, "")?;
+            written.push(path);
+        }
+    }
+
+    let answers_path = examples_dir.join(examples::ANSWERS_FILE);
+    let answers = fs::read_to_string(&answers_path).unwrap_or_default();
+    let table = format!("[day{}-1]", padded);
+    if !answers.lines().any(|line| line.trim() == table) {
+        let separator = if answers.is_empty() { "" } else { "\n" };
+        let entry = format!("{}{}\n# part1 = \n# part2 = \n", separator, table);
+        write(&answers_path, &(answers + &entry))?;
+        written.push(answers_path);
+    }
+
+    Ok(written)
+}
+
+/// Inserts `line` among the lines matching `pattern`, keeping them ordered by
+/// the numbers captured from each, compared in capture order. Returns `None`
+/// when nothing matches.
+fn insert_sorted(text: &str, line: &str, pattern: &str, key: &[u32]) -> Option<String> {
+    let re = Regex::new(pattern).unwrap();
+    let mut lines: Vec<&str> = text.lines().collect();
+
+    let keyed: Vec<(usize, Vec<u32>)> = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(index, line)| {
+            let captures = re.captures(line)?;
+            let numbers = captures
+                .iter()
+                .skip(1)
+                .map(|group| group?.as_str().parse().ok())
+                .collect::<Option<Vec<u32>>>()?;
+            Some((index, numbers))
+        })
+        .collect();
+
+    let last_index = keyed.last()?.0;
+    let position = keyed
+        .iter()
+        .find(|(_, existing)| existing.as_slice() > key)
+        .map_or(last_index + 1, |(index, _)| *index);
+
+    lines.insert(position, line);
+
+    let mut updated = lines.join("\n");
+    if text.ends_with('\n') {
+        updated.push('\n');
+    }
+    Some(updated)
+}
+
+fn create_parent(path: &Path) -> Result<(), String> {
+    match path.parent() {
+        Some(parent) => fs::create_dir_all(parent)
+            .map_err(|err| format!("Failed to create {}: {}", parent.display(), err)),
+        None => Ok(()),
+    }
+}
+
+fn read(path: &Path) -> Result<String, String> {
+    fs::read_to_string(path).map_err(|err| format!("Failed to read {}: {}", path.display(), err))
+}
+
+fn write(path: &Path, contents: &str) -> Result<(), String> {
+    fs::write(path, contents).map_err(|err| format!("Failed to write {}: {}", path.display(), err))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_insert_sorted() {
+        let text = "pub mod cli;\npub mod day01;\npub mod day07;\npub mod input;\n";
+        let pattern = r"^pub mod day(\d+);$";
+
+        assert_eq!(
+            insert_sorted(text, "pub mod day03;", pattern, &[3]).unwrap(),
+            "pub mod cli;\npub mod day01;\npub mod day03;\npub mod day07;\npub mod input;\n"
+        );
+        assert_eq!(
+            insert_sorted(text, "pub mod day09;", pattern, &[9]).unwrap(),
+            "pub mod cli;\npub mod day01;\npub mod day07;\npub mod day09;\npub mod input;\n"
+        );
+        assert_eq!(
+            insert_sorted("pub mod cli;\n", "pub mod day01;", pattern, &[1]),
+            None
+        );
+
+        let registry = "    new(2023, 25),\n    new(2024, 1),\n";
+        assert_eq!(
+            insert_sorted(
+                registry,
+                "    new(2023, 3),",
+                r"new\((\d+), (\d+)\)",
+                &[2023, 3]
+            )
+            .unwrap(),
+            "    new(2023, 3),\n    new(2023, 25),\n    new(2024, 1),\n"
+        );
+    }
+
+    #[test]
+    fn test_create_day() {
+        let root = std::env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        fs::create_dir_all(root.join("src")).unwrap();
+        fs::write(
+            root.join("src/lib.rs"),
+            "pub mod registry;\npub mod year2024;\n",
+        )
+        .unwrap();
+        fs::write(root.join("src/year2024.rs"), "pub mod day01;\n").unwrap();
+        fs::write(
+            root.join("src/registry.rs"),
+            "pub const DAYS: &[RegisteredDay] = &[\n    RegisteredDay::new(2024, 1, &crate::year2024::day01::Day01),\n];\n",
+        )
+        .unwrap();
+
+        let written = create_day(&root, 2024, 14).unwrap();
+
+        assert_eq!(written.len(), 6);
+        assert!(fs::read_to_string(root.join("src/year2024/day14.rs"))
+            .unwrap()
+            .contains("pub struct Day14;"));
+        assert_eq!(
+            fs::read_to_string(root.join("src/year2024.rs")).unwrap(),
+            "pub mod day01;\npub mod day14;\n"
+        );
+        assert!(fs::read_to_string(root.join("src/registry.rs"))
+            .unwrap()
+            .contains("    RegisteredDay::new(2024, 14, &crate::year2024::day14::Day14),\n];"));
+        assert!(root.join("inputs/2024/day14.txt").exists());
+        assert!(root.join("inputs/2024/examples/day14-1.txt").exists());
+        assert_eq!(
+            fs::read_to_string(root.join("inputs/2024/examples/answers.toml")).unwrap(),
+            "[day14-1]\n# part1 = \n# part2 = \n"
+        );
+        assert!(create_day(&root, 2024, 14).is_err());
+
+        let written = create_day(&root, 2023, 1).unwrap();
+
+        assert_eq!(written.len(), 7);
+        assert_eq!(
+            fs::read_to_string(root.join("src/year2023.rs")).unwrap(),
+            "//! Advent of Code 2023.\n\npub mod day01;\n"
+        );
+        assert_eq!(
+            fs::read_to_string(root.join("src/lib.rs")).unwrap(),
+            "pub mod registry;\npub mod year2023;\npub mod year2024;\n"
+        );
+        assert!(fs::read_to_string(root.join("src/registry.rs"))
+            .unwrap()
+            .contains("[\n    RegisteredDay::new(2023, 1, &crate::year2023::day01::Day01),\n"));
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,294 @@
+//! Graph searches over an implicit graph described by a neighbor function.
+//!
+//! Nodes are any small `Copy` value, typically a `grid::Position` or a
+//! `(Position, Direction)` state. Each search returns a [`SearchResult`]
+//! holding the distance and predecessor of every reached node, from which
+//! paths can be rebuilt.
+
+use std::cmp::Reverse;
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+
+#[derive(Debug, Clone)]
+pub struct SearchResult<N> {
+    /// Cost of the best known path from a start node. For BFS and DFS this
+    /// is the number of edges followed.
+    pub distances: HashMap<N, u64>,
+    /// The node each reached node was first reached from. Start nodes have
+    /// no entry.
+    pub predecessors: HashMap<N, N>,
+    /// Reached nodes in the order the search settled them.
+    pub order: Vec<N>,
+}
+
+impl<N: Copy + Eq + Hash> SearchResult<N> {
+    fn new() -> SearchResult<N> {
+        SearchResult {
+            distances: HashMap::new(),
+            predecessors: HashMap::new(),
+            order: Vec::new(),
+        }
+    }
+
+    pub fn distance(&self, node: N) -> Option<u64> {
+        self.distances.get(&node).copied()
+    }
+
+    pub fn contains(&self, node: N) -> bool {
+        self.distances.contains_key(&node)
+    }
+
+    /// The path from a start node to `goal`, both ends included.
+    pub fn path_to(&self, goal: N) -> Option<Vec<N>> {
+        if !self.contains(goal) {
+            return None;
+        }
+
+        let mut path = vec![goal];
+        let mut node = goal;
+        while let Some(&previous) = self.predecessors.get(&node) {
+            path.push(previous);
+            node = previous;
+        }
+        path.reverse();
+
+        Some(path)
+    }
+}
+
+/// Breadth-first search, so distances count the fewest edges to each node.
+pub fn bfs<N, F, I>(starts: impl IntoIterator<Item = N>, mut neighbors: F) -> SearchResult<N>
+where
+    N: Copy + Eq + Hash,
+    F: FnMut(N) -> I,
+    I: IntoIterator<Item = N>,
+{
+    let mut result = SearchResult::new();
+    let mut queue = VecDeque::new();
+
+    for start in starts {
+        if result.distances.insert(start, 0).is_none() {
+            queue.push_back(start);
+        }
+    }
+
+    while let Some(node) = queue.pop_front() {
+        result.order.push(node);
+        let distance = result.distances[&node] + 1;
+
+        for next in neighbors(node) {
+            if let Entry::Vacant(entry) = result.distances.entry(next) {
+                entry.insert(distance);
+                result.predecessors.insert(next, node);
+                queue.push_back(next);
+            }
+        }
+    }
+
+    result
+}
+
+/// Depth-first search. `order` is the pre-order visit sequence and
+/// distances are depths in the resulting search tree, not shortest paths.
+pub fn dfs<N, F, I>(starts: impl IntoIterator<Item = N>, mut neighbors: F) -> SearchResult<N>
+where
+    N: Copy + Eq + Hash,
+    F: FnMut(N) -> I,
+    I: IntoIterator<Item = N>,
+{
+    let mut result = SearchResult::new();
+    let mut stack: Vec<(N, Option<N>, u64)> =
+        starts.into_iter().map(|start| (start, None, 0)).collect();
+    stack.reverse();
+
+    while let Some((node, previous, depth)) = stack.pop() {
+        if let Entry::Vacant(entry) = result.distances.entry(node) {
+            entry.insert(depth);
+        } else {
+            continue;
+        }
+        if let Some(previous) = previous {
+            result.predecessors.insert(node, previous);
+        }
+        result.order.push(node);
+
+        let pending = stack.len();
+        for next in neighbors(node) {
+            if !result.distances.contains_key(&next) {
+                stack.push((next, Some(node), depth + 1));
+            }
+        }
+        // Visit neighbors in the order they were produced.
+        stack[pending..].reverse();
+    }
+
+    result
+}
+
+/// Dijkstra's algorithm over non-negative edge costs. `neighbors` yields
+/// each neighbor together with the cost of the edge leading to it.
+pub fn dijkstra<N, F, I>(starts: impl IntoIterator<Item = N>, neighbors: F) -> SearchResult<N>
+where
+    N: Copy + Eq + Hash,
+    F: FnMut(N) -> I,
+    I: IntoIterator<Item = (N, u64)>,
+{
+    best_first(starts, neighbors, |_| 0, |_| false).0
+}
+
+/// A* search from `start` until a node satisfying `is_goal` is settled.
+/// `heuristic` must never overestimate the remaining cost. Returns the cost
+/// and path to the goal that was reached.
+pub fn astar<N, F, I>(
+    start: N,
+    neighbors: F,
+    heuristic: impl FnMut(N) -> u64,
+    is_goal: impl FnMut(N) -> bool,
+) -> Option<(u64, Vec<N>)>
+where
+    N: Copy + Eq + Hash,
+    F: FnMut(N) -> I,
+    I: IntoIterator<Item = (N, u64)>,
+{
+    let (result, goal) = best_first([start], neighbors, heuristic, is_goal);
+    let goal = goal?;
+
+    Some((result.distances[&goal], result.path_to(goal)?))
+}
+
+fn best_first<N, F, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbors: F,
+    mut heuristic: impl FnMut(N) -> u64,
+    mut is_goal: impl FnMut(N) -> bool,
+) -> (SearchResult<N>, Option<N>)
+where
+    N: Copy + Eq + Hash,
+    F: FnMut(N) -> I,
+    I: IntoIterator<Item = (N, u64)>,
+{
+    let mut result = SearchResult::new();
+    let mut settled = HashSet::new();
+    // The heap stores indices into `nodes` so that `N` does not need `Ord`.
+    let mut nodes = Vec::new();
+    let mut heap = BinaryHeap::new();
+
+    for start in starts {
+        if result.distances.insert(start, 0).is_none() {
+            heap.push(Reverse((heuristic(start), 0, nodes.len())));
+            nodes.push(start);
+        }
+    }
+
+    while let Some(Reverse((_, distance, index))) = heap.pop() {
+        let node = nodes[index];
+        if distance > result.distances[&node] || !settled.insert(node) {
+            continue;
+        }
+        result.order.push(node);
+
+        if is_goal(node) {
+            return (result, Some(node));
+        }
+
+        for (next, cost) in neighbors(node) {
+            let next_distance = distance + cost;
+            let improved = result
+                .distances
+                .get(&next)
+                .is_none_or(|&known| next_distance < known);
+
+            if improved {
+                result.distances.insert(next, next_distance);
+                result.predecessors.insert(next, node);
+                heap.push(Reverse((
+                    next_distance + heuristic(next),
+                    next_distance,
+                    nodes.len(),
+                )));
+                nodes.push(next);
+            }
+        }
+    }
+
+    (result, None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grid::{Grid, Position};
+
+    const TEST_MAZE: &str = "..#.
+.#..
+....";
+
+    fn open_neighbors(grid: &Grid<char>, position: Position) -> Vec<Position> {
+        grid.neighbors4(position)
+            .filter(|&neighbor| grid[neighbor] == '.')
+            .collect()
+    }
+
+    #[test]
+    fn test_bfs() {
+        let grid = Grid::parse_chars(TEST_MAZE).unwrap();
+        let result = bfs([Position::new(0, 0)], |position| {
+            open_neighbors(&grid, position)
+        });
+
+        assert_eq!(result.distance(Position::new(3, 0)), Some(7));
+        assert_eq!(result.distance(Position::new(2, 0)), None);
+        assert_eq!(result.order.len(), 10);
+
+        let path = result.path_to(Position::new(3, 2)).unwrap();
+        assert_eq!(path.len(), 6);
+        assert_eq!(path[0], Position::new(0, 0));
+    }
+
+    #[test]
+    fn test_dfs() {
+        let edges = HashMap::from([(1, vec![2, 3]), (2, vec![4]), (3, vec![4]), (4, vec![])]);
+        let result = dfs([1], |node| edges[&node].clone());
+
+        assert_eq!(result.order, [1, 2, 4, 3]);
+        assert_eq!(result.path_to(4), Some(vec![1, 2, 4]));
+        assert_eq!(result.distance(3), Some(1));
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        let edges = HashMap::from([
+            ('a', vec![('b', 7), ('c', 2)]),
+            ('b', vec![('d', 1)]),
+            ('c', vec![('b', 3), ('d', 8)]),
+            ('d', vec![]),
+        ]);
+        let result = dijkstra(['a'], |node| edges[&node].clone());
+
+        assert_eq!(result.distance('b'), Some(5));
+        assert_eq!(result.distance('d'), Some(6));
+        assert_eq!(result.path_to('d'), Some(vec!['a', 'c', 'b', 'd']));
+    }
+
+    #[test]
+    fn test_astar() {
+        let grid = Grid::parse_chars(TEST_MAZE).unwrap();
+        let goal = Position::new(3, 0);
+        let (cost, path) = astar(
+            Position::new(0, 0),
+            |position| {
+                open_neighbors(&grid, position)
+                    .into_iter()
+                    .map(|neighbor| (neighbor, 1))
+            },
+            |position| position.manhattan_distance(goal) as u64,
+            |position| position == goal,
+        )
+        .unwrap();
+
+        assert_eq!(cost, 7);
+        assert_eq!(path.last(), Some(&goal));
+        assert!(astar(Position::new(0, 0), |_| [], |_| 0, |_| false).is_none());
+    }
+}
@@ -0,0 +1,213 @@
+use std::collections::BTreeMap;
+use std::fmt;
+
+use crate::error::{ParseError, ParseResult};
+
+/// A puzzle answer, as produced by either part of a [`Solution`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Number(u64),
+    Text(String),
+    /// The part has not been solved yet. Kept distinct from any real answer,
+    /// including zero.
+    Unimplemented,
+    /// The input has no answer for this part, for the reason given. Never
+    /// mistaken for a real answer, so it is not submitted.
+    NoSolution(String),
+}
+
+impl Answer {
+    pub fn is_implemented(&self) -> bool {
+        *self != Answer::Unimplemented
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Number(number) => write!(f, "{}", number),
+            Answer::Text(text) => write!(f, "{}", text),
+            Answer::Unimplemented => write!(f, "not implemented"),
+            Answer::NoSolution(reason) => write!(f, "no solution: {}", reason),
+        }
+    }
+}
+
+impl PartialEq<u64> for Answer {
+    fn eq(&self, other: &u64) -> bool {
+        *self == Answer::Number(*other)
+    }
+}
+
+impl From<u32> for Answer {
+    fn from(value: u32) -> Self {
+        Answer::Number(value as u64)
+    }
+}
+
+impl From<u64> for Answer {
+    fn from(value: u64) -> Self {
+        Answer::Number(value)
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(value: usize) -> Self {
+        Answer::Number(value as u64)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+
+/// One of the two halves of a day's puzzle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Part::One => write!(f, "1"),
+            Part::Two => write!(f, "2"),
+        }
+    }
+}
+
+/// Values given with `--set name=value`, for puzzles whose input leaves out
+/// something the answers depend on, like the size of a grid.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Settings {
+    values: BTreeMap<String, u64>,
+}
+
+impl Settings {
+    pub fn set(&mut self, name: &str, value: u64) {
+        self.values.insert(name.to_string(), value);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// The value of `name`, or `default` when it was not given. Fails if the
+    /// value does not fit in a `T`.
+    pub fn get_or<T: TryFrom<u64>>(&self, name: &str, default: T) -> ParseResult<T> {
+        match self.values.get(name) {
+            Some(&value) => T::try_from(value).map_err(|_| {
+                ParseError::new(1, 1, format!("Setting {}={} is too large", name, value))
+            }),
+            None => Ok(default),
+        }
+    }
+
+    /// The names that were given but are not in `known`.
+    pub fn unknown<'a>(&'a self, known: &[&str]) -> Vec<&'a str> {
+        self.values
+            .keys()
+            .map(String::as_str)
+            .filter(|name| !known.contains(name))
+            .collect()
+    }
+}
+
+/// A day's puzzle: the input is parsed once and shared by both parts.
+pub trait Solution {
+    type Input;
+
+    /// The names of the [`Settings`] that [`Solution::configure`] reads.
+    const SETTINGS: &'static [&'static str] = &[];
+
+    fn parse(input: &str) -> ParseResult<Self::Input>;
+
+    /// Applies `settings` to the parsed input. Settings left out keep
+    /// whatever `parse` chose.
+    fn configure(input: Self::Input, _settings: &Settings) -> ParseResult<Self::Input> {
+        Ok(input)
+    }
+
+    fn part1(input: &Self::Input) -> Answer;
+    fn part2(input: &Self::Input) -> Answer;
+
+    /// Parses `input` and runs part 1 on it in one step.
+    ///
+    /// Panics if `input` does not parse.
+    fn solve_part1(input: &str) -> Answer {
+        Self::part1(&parse_or_panic::<Self>(input))
+    }
+
+    /// Parses `input` and runs part 2 on it in one step.
+    ///
+    /// Panics if `input` does not parse.
+    fn solve_part2(input: &str) -> Answer {
+        Self::part2(&parse_or_panic::<Self>(input))
+    }
+}
+
+fn parse_or_panic<S: Solution + ?Sized>(input: &str) -> S::Input {
+    S::parse(input).unwrap_or_else(|err| panic!("Invalid input: {}", err))
+}
+
+/// Object-safe view of a [`Solution`], so days with different input types can
+/// live side by side in the registry.
+pub trait Runnable: Sync {
+    /// The names of the settings the day accepts.
+    fn settings(&self) -> &'static [&'static str];
+
+    fn parse(&self, input: &str, settings: &Settings) -> ParseResult<Box<dyn Parsed>>;
+}
+
+/// Parsed input of a type-erased [`Solution`], ready to have either part run.
+pub trait Parsed {
+    fn part1(&self) -> Answer;
+    fn part2(&self) -> Answer;
+
+    fn solve(&self, part: Part) -> Answer {
+        match part {
+            Part::One => self.part1(),
+            Part::Two => self.part2(),
+        }
+    }
+}
+
+struct ParsedInput<S: Solution>(S::Input);
+
+impl<S: Solution> Parsed for ParsedInput<S> {
+    fn part1(&self) -> Answer {
+        S::part1(&self.0)
+    }
+
+    fn part2(&self) -> Answer {
+        S::part2(&self.0)
+    }
+}
+
+impl<S> Runnable for S
+where
+    S: Solution + Sync + 'static,
+    S::Input: 'static,
+{
+    fn settings(&self) -> &'static [&'static str] {
+        S::SETTINGS
+    }
+
+    fn parse(&self, input: &str, settings: &Settings) -> ParseResult<Box<dyn Parsed>> {
+        let parsed = S::configure(S::parse(input)?, settings)?;
+        Ok(Box::new(ParsedInput::<S>(parsed)))
+    }
+}
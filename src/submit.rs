@@ -0,0 +1,542 @@
+//! Submits answers and keeps a history of every attempt, so that answers the
+//! server already rejected are never sent again and its cooldown after a
+//! wrong answer is respected locally.
+
+use std::fmt;
+use std::fs::{self, OpenOptions};
+use std::io::{ErrorKind, Write};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use crate::input;
+use crate::provider::HttpFetcher;
+use crate::solution::Part;
+
+pub const HISTORY_FILE: &str = "submissions.txt";
+
+/// How long the server makes us wait after a wrong answer, unless its
+/// response says otherwise. The wait grows after repeated wrong answers.
+pub const WRONG_ANSWER_COOLDOWN: Duration = Duration::from_secs(60);
+
+/// The submission history for `year`, e.g. `inputs/2024/submissions.txt`.
+pub fn history_path(year: u32) -> PathBuf {
+    input::year_dir(year).join(HISTORY_FILE)
+}
+
+/// What the server said about a submitted answer. Wrong answers hold how
+/// long the server asked us to wait before the next one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    TooHigh(Duration),
+    TooLow(Duration),
+    /// Wrong, without a hint about which direction.
+    Wrong(Duration),
+    /// Not checked because an answer was submitted too recently; holds how
+    /// long the server asked us to wait.
+    RateLimited(Duration),
+    /// The part was already solved, so the answer was not checked.
+    AlreadySolved,
+    /// A response this client does not recognise.
+    Unknown,
+}
+
+impl Verdict {
+    /// Reads the verdict from the HTML page the server responds with.
+    pub fn from_response(body: &str) -> Verdict {
+        if body.contains("That's the right answer") {
+            Verdict::Correct
+        } else if body.contains("That's not the right answer") {
+            let wait = parse_penalty(body).unwrap_or(WRONG_ANSWER_COOLDOWN);
+            if body.contains("your answer is too high") {
+                Verdict::TooHigh(wait)
+            } else if body.contains("your answer is too low") {
+                Verdict::TooLow(wait)
+            } else {
+                Verdict::Wrong(wait)
+            }
+        } else if body.contains("You gave an answer too recently") {
+            Verdict::RateLimited(parse_wait(body).unwrap_or(WRONG_ANSWER_COOLDOWN))
+        } else if body.contains("You don't seem to be solving the right level") {
+            Verdict::AlreadySolved
+        } else {
+            Verdict::Unknown
+        }
+    }
+
+    pub fn is_wrong(self) -> bool {
+        matches!(
+            self,
+            Verdict::TooHigh(_) | Verdict::TooLow(_) | Verdict::Wrong(_)
+        )
+    }
+
+    /// How long to wait before submitting again after this verdict.
+    pub fn cooldown(self) -> Duration {
+        match self {
+            Verdict::TooHigh(wait)
+            | Verdict::TooLow(wait)
+            | Verdict::Wrong(wait)
+            | Verdict::RateLimited(wait) => wait,
+            _ => Duration::ZERO,
+        }
+    }
+
+    /// The verdict as written in the history file. Verdicts with a wait
+    /// append it in seconds, as in `too-high:300`.
+    fn name(self) -> String {
+        let name = match self {
+            Verdict::Correct => "correct",
+            Verdict::TooHigh(_) => "too-high",
+            Verdict::TooLow(_) => "too-low",
+            Verdict::Wrong(_) => "wrong",
+            Verdict::RateLimited(_) => "rate-limited",
+            Verdict::AlreadySolved => "already-solved",
+            Verdict::Unknown => "unknown",
+        };
+
+        match self.cooldown() {
+            Duration::ZERO => name.to_string(),
+            wait => format!("{}:{}", name, wait.as_secs()),
+        }
+    }
+
+    /// Reads a verdict written by [`Verdict::name`]. Wrong answers recorded
+    /// without a wait get the default cooldown.
+    fn from_name(name: &str) -> Option<Verdict> {
+        let (name, wait) = match name.split_once(':') {
+            Some((name, seconds)) => (name, Some(Duration::from_secs(seconds.parse().ok()?))),
+            None => (name, None),
+        };
+        let wrong_wait = wait.unwrap_or(WRONG_ANSWER_COOLDOWN);
+
+        match name {
+            "correct" => Some(Verdict::Correct),
+            "too-high" => Some(Verdict::TooHigh(wrong_wait)),
+            "too-low" => Some(Verdict::TooLow(wrong_wait)),
+            "wrong" => Some(Verdict::Wrong(wrong_wait)),
+            "rate-limited" => Some(Verdict::RateLimited(wait.unwrap_or_default())),
+            "already-solved" => Some(Verdict::AlreadySolved),
+            "unknown" => Some(Verdict::Unknown),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::TooHigh(_) => write!(f, "too high"),
+            Verdict::TooLow(_) => write!(f, "too low"),
+            Verdict::Wrong(_) => write!(f, "wrong"),
+            Verdict::RateLimited(wait) => {
+                write!(
+                    f,
+                    "not checked, wait {}s before trying again",
+                    wait.as_secs()
+                )
+            }
+            Verdict::AlreadySolved => write!(f, "already solved"),
+            Verdict::Unknown => write!(f, "unrecognised response"),
+        }
+    }
+}
+
+/// Parses the `You have 1m 30s left to wait` note of a rate-limited response.
+fn parse_wait(body: &str) -> Option<Duration> {
+    let before = &body[..body.find(" left to wait")?];
+    let amounts = &before[before.rfind("You have ")? + "You have ".len()..];
+
+    let mut seconds = 0;
+    for amount in amounts.split_whitespace() {
+        let (value, scale) = if let Some(minutes) = amount.strip_suffix('m') {
+            (minutes, 60)
+        } else {
+            (amount.strip_suffix('s')?, 1)
+        };
+        seconds += value.parse::<u64>().ok()? * scale;
+    }
+
+    Some(Duration::from_secs(seconds))
+}
+
+/// Parses the `please wait 5 minutes before trying again` note of a wrong
+/// answer response. The server spells out small numbers, as in `one minute`.
+fn parse_penalty(body: &str) -> Option<Duration> {
+    let body = body.to_lowercase();
+    let after = &body[body.find("please wait ")? + "please wait ".len()..];
+    let mut words = after.split_whitespace();
+
+    let amount = words.next()?;
+    let amount = match amount {
+        "one" => 1,
+        "two" => 2,
+        "three" => 3,
+        "four" => 4,
+        "five" => 5,
+        "six" => 6,
+        "seven" => 7,
+        "eight" => 8,
+        "nine" => 9,
+        "ten" => 10,
+        _ => amount.parse().ok()?,
+    };
+    let scale = match words.next()?.trim_end_matches('s') {
+        "second" => 1,
+        "minute" => 60,
+        "hour" => 60 * 60,
+        _ => return None,
+    };
+
+    Some(Duration::from_secs(amount * scale))
+}
+
+/// Sends an answer to the server and returns its response page.
+pub trait Submitter {
+    fn submit(&self, year: u32, day: u32, part: Part, answer: &str) -> Result<String, String>;
+}
+
+impl Submitter for HttpFetcher {
+    fn submit(&self, year: u32, day: u32, part: Part, answer: &str) -> Result<String, String> {
+        let path = format!("/{}/day/{}/answer", year, day);
+        let level = part.to_string();
+
+        self.request("POST", &path)
+            .send_form(&[("level", &level), ("answer", answer)])
+            .map_err(|err| format!("Failed to submit to {}: {}", path, err))?
+            .into_string()
+            .map_err(|err| format!("Failed to read the response to {}: {}", path, err))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Attempt {
+    /// Seconds since the Unix epoch.
+    pub time: u64,
+    pub day: u32,
+    pub part: Part,
+    pub answer: String,
+    pub verdict: Verdict,
+}
+
+/// Every answer submitted for a year, oldest first, backed by a file.
+///
+/// The file has one attempt per line, written as
+/// `<unix seconds> <day> <part> <verdict> <answer>`.
+#[derive(Debug)]
+pub struct History {
+    path: PathBuf,
+    attempts: Vec<Attempt>,
+}
+
+impl History {
+    /// Loads the history at `path`, which is empty if the file is missing.
+    /// Any other read failure is an error, since submitting without the
+    /// history could resend an answer that was already rejected.
+    pub fn load(path: &Path) -> Result<History, String> {
+        let attempts = match fs::read_to_string(path) {
+            Ok(text) => {
+                parse_attempts(&text).map_err(|err| format!("{}: {}", path.display(), err))?
+            }
+            Err(err) if err.kind() == ErrorKind::NotFound => Vec::new(),
+            Err(err) => {
+                return Err(format!(
+                    "Failed to read history {}: {}",
+                    path.display(),
+                    err
+                ))
+            }
+        };
+
+        Ok(History {
+            path: path.to_path_buf(),
+            attempts,
+        })
+    }
+
+    pub fn attempts(&self) -> &[Attempt] {
+        &self.attempts
+    }
+
+    /// Adds `attempt` to the history and appends it to the file.
+    pub fn record(&mut self, attempt: Attempt) -> Result<(), String> {
+        let path = &self.path;
+        let line = format!(
+            "{} {} {} {} {}\n",
+            attempt.time,
+            attempt.day,
+            attempt.part,
+            attempt.verdict.name(),
+            attempt.answer
+        );
+
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .map_err(|err| format!("Failed to create {}: {}", parent.display(), err))?;
+        }
+        OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .and_then(|mut file| file.write_all(line.as_bytes()))
+            .map_err(|err| format!("Failed to write history {}: {}", path.display(), err))?;
+
+        self.attempts.push(attempt);
+        Ok(())
+    }
+
+    /// Explains why `answer` should not be submitted at time `now`, if the
+    /// history already settles it or the cooldown has not passed yet.
+    pub fn refusal(&self, day: u32, part: Part, answer: &str, now: u64) -> Option<String> {
+        let attempts: Vec<&Attempt> = self
+            .attempts
+            .iter()
+            .filter(|attempt| attempt.day == day && attempt.part == part)
+            .collect();
+
+        if let Some(correct) = attempts
+            .iter()
+            .find(|attempt| attempt.verdict == Verdict::Correct)
+        {
+            return Some(format!(
+                "Day {} part {} is already solved, the answer was {}",
+                day, part, correct.answer
+            ));
+        }
+
+        if let Some(rejected) = attempts
+            .iter()
+            .find(|attempt| attempt.verdict.is_wrong() && attempt.answer == answer)
+        {
+            return Some(format!(
+                "{} was already rejected as {}",
+                answer, rejected.verdict
+            ));
+        }
+
+        if let Ok(value) = answer.parse::<i64>() {
+            for attempt in &attempts {
+                let Ok(previous) = attempt.answer.parse::<i64>() else {
+                    continue;
+                };
+                let out_of_bounds = match attempt.verdict {
+                    Verdict::TooHigh(_) => value >= previous,
+                    Verdict::TooLow(_) => value <= previous,
+                    _ => false,
+                };
+                if out_of_bounds {
+                    return Some(format!(
+                        "{} cannot be right, {} was already {}",
+                        answer, previous, attempt.verdict
+                    ));
+                }
+            }
+        }
+
+        let last = self.attempts.last()?;
+        let ready = last.time + last.verdict.cooldown().as_secs();
+        (now < ready).then(|| {
+            format!(
+                "The last answer was submitted too recently, wait {}s",
+                ready - now
+            )
+        })
+    }
+}
+
+/// Submits `answer` unless the history refuses it, then records the attempt.
+pub fn submit_answer(
+    submitter: &dyn Submitter,
+    history: &mut History,
+    year: u32,
+    day: u32,
+    part: Part,
+    answer: &str,
+    now: u64,
+) -> Result<Verdict, String> {
+    if let Some(reason) = history.refusal(day, part, answer, now) {
+        return Err(reason);
+    }
+
+    let response = submitter.submit(year, day, part, answer)?;
+    let verdict = Verdict::from_response(&response);
+
+    history.record(Attempt {
+        time: now,
+        day,
+        part,
+        answer: answer.to_string(),
+        verdict,
+    })?;
+
+    Ok(verdict)
+}
+
+fn parse_attempts(text: &str) -> Result<Vec<Attempt>, String> {
+    text.lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(index, line)| {
+            parse_attempt(line.trim()).ok_or_else(|| {
+                format!(
+                    "line {}: expected <time> <day> <part> <verdict> <answer>",
+                    index + 1
+                )
+            })
+        })
+        .collect()
+}
+
+fn parse_attempt(line: &str) -> Option<Attempt> {
+    let fields: Vec<&str> = line.splitn(5, ' ').collect();
+    let [time, day, part, verdict, answer] = fields.as_slice() else {
+        return None;
+    };
+
+    Some(Attempt {
+        time: time.parse().ok()?,
+        day: day.parse().ok()?,
+        part: match *part {
+            "1" => Part::One,
+            "2" => Part::Two,
+            _ => return None,
+        },
+        answer: answer.to_string(),
+        verdict: Verdict::from_name(verdict)?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mock_server::MockServer;
+
+    const TOO_HIGH: &str = "<article><p>That's not the right answer; your answer is too high. \
+        Please wait one minute before trying again.</p></article>";
+    const RIGHT: &str =
+        "<article><p>That's the right answer! You are one gold star closer.</p></article>";
+    const TOO_LOW_REPEATED: &str = "<article><p>That's not the right answer; your answer is too \
+        low. Because you have guessed incorrectly 4 times on this puzzle, please wait 5 minutes \
+        before trying again.</p></article>";
+    const TOO_RECENT: &str = "<article><p>You gave an answer too recently; you have to wait \
+        after submitting an answer before trying again.  You have 1m 5s left to wait.</p></article>";
+
+    #[test]
+    fn test_verdict_from_response() {
+        assert_eq!(
+            Verdict::from_response(TOO_HIGH),
+            Verdict::TooHigh(Duration::from_secs(60))
+        );
+        assert_eq!(
+            Verdict::from_response(TOO_LOW_REPEATED),
+            Verdict::TooLow(Duration::from_secs(300))
+        );
+        assert_eq!(Verdict::from_response(RIGHT), Verdict::Correct);
+        assert_eq!(
+            Verdict::from_response(TOO_RECENT),
+            Verdict::RateLimited(Duration::from_secs(65))
+        );
+        assert_eq!(
+            Verdict::from_response("You don't seem to be solving the right level."),
+            Verdict::AlreadySolved
+        );
+        assert_eq!(Verdict::from_response("<html></html>"), Verdict::Unknown);
+    }
+
+    fn history_from(text: &str) -> History {
+        History {
+            path: PathBuf::new(),
+            attempts: parse_attempts(text).unwrap(),
+        }
+    }
+
+    #[test]
+    fn test_parse_attempts() {
+        let text = "100 1 1 too-low 12\n160 1 1 rate-limited:30 13\n200 1 1 correct 1,2,3\n\
+            300 1 2 wrong:300 7\n";
+        let attempts = parse_attempts(text).unwrap();
+
+        assert_eq!(attempts.len(), 4);
+        assert_eq!(attempts[0].verdict, Verdict::TooLow(WRONG_ANSWER_COOLDOWN));
+        assert_eq!(
+            attempts[1].verdict,
+            Verdict::RateLimited(Duration::from_secs(30))
+        );
+        assert_eq!(attempts[2].answer, "1,2,3");
+        assert_eq!(
+            attempts[3].verdict,
+            Verdict::Wrong(Duration::from_secs(300))
+        );
+        assert_eq!(
+            Verdict::from_name(&attempts[3].verdict.name()),
+            Some(attempts[3].verdict)
+        );
+        assert!(parse_attempts("100 1 3 correct 12").is_err());
+    }
+
+    #[test]
+    fn test_refusal() {
+        let history = history_from("100 5 1 too-high 500\n120 5 1 too-low 100\n");
+
+        assert!(history.refusal(5, Part::One, "500", 1000).is_some());
+        assert!(history.refusal(5, Part::One, "600", 1000).is_some());
+        assert!(history.refusal(5, Part::One, "50", 1000).is_some());
+        assert!(history.refusal(5, Part::One, "300", 150).is_some());
+        assert_eq!(history.refusal(5, Part::One, "300", 180), None);
+        assert_eq!(history.refusal(5, Part::Two, "500", 180), None);
+
+        let solved = history_from("100 5 1 correct 300\n");
+        assert!(solved.refusal(5, Part::One, "300", 1000).is_some());
+
+        let penalised = history_from("100 5 2 wrong:300 7\n");
+        assert!(penalised.refusal(5, Part::Two, "8", 399).is_some());
+        assert_eq!(penalised.refusal(5, Part::Two, "8", 400), None);
+    }
+
+    #[test]
+    fn test_submit_answer() {
+        let path = std::env::temp_dir().join(format!("aoc-submit-{}.txt", std::process::id()));
+        let _ = fs::remove_file(&path);
+        let server = MockServer::start(vec![(200, TOO_HIGH), (200, RIGHT)]);
+        let client = HttpFetcher::new(&server.url, "secret");
+        let mut history = History::load(&path).unwrap();
+
+        let submit = |history: &mut History, answer: &str, now: u64| {
+            submit_answer(&client, history, 2024, 3, Part::Two, answer, now)
+        };
+
+        assert_eq!(
+            submit(&mut history, "99", 1000),
+            Ok(Verdict::TooHigh(Duration::from_secs(60)))
+        );
+        assert!(submit(&mut history, "99", 2000).is_err());
+        assert!(submit(&mut history, "42", 1030).is_err());
+        assert_eq!(submit(&mut history, "42", 1060), Ok(Verdict::Correct));
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[0].line, "POST /2024/day/3/answer HTTP/1.1");
+        assert_eq!(requests[0].header("Cookie"), Some("session=secret"));
+        assert_eq!(requests[0].body, "level=2&answer=99");
+        assert_eq!(
+            fs::read_to_string(&path).unwrap(),
+            "1000 3 2 too-high:60 99\n1060 3 2 correct 42\n"
+        );
+        assert_eq!(History::load(&path).unwrap().attempts(), history.attempts());
+
+        fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_load_fails_on_unreadable_history() {
+        let dir = std::env::temp_dir().join(format!("aoc-history-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+
+        assert!(History::load(&dir.join("missing.txt")).is_ok());
+        // A directory cannot be read as a file.
+        assert!(History::load(&dir).is_err());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
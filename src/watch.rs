@@ -0,0 +1,170 @@
+use std::collections::HashMap;
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::{Duration, SystemTime};
+
+use crate::examples;
+use crate::scaffold;
+
+/// How often `watch` checks the files for changes.
+pub const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Detects changes to a set of files by polling their modification times.
+#[derive(Debug, Default)]
+pub struct Watcher {
+    modified: HashMap<PathBuf, Option<SystemTime>>,
+}
+
+impl Watcher {
+    pub fn new() -> Watcher {
+        Watcher::default()
+    }
+
+    /// Returns the paths whose modification time changed since the last
+    /// poll, including files that were created, deleted or dropped from
+    /// `paths`. The first poll reports every path.
+    pub fn poll(&mut self, paths: &[PathBuf]) -> Vec<PathBuf> {
+        let current: HashMap<PathBuf, Option<SystemTime>> = paths
+            .iter()
+            .map(|path| (path.clone(), modified(path)))
+            .collect();
+
+        let mut changed: Vec<PathBuf> = current
+            .iter()
+            .filter(|(path, time)| self.modified.get(*path) != Some(*time))
+            .map(|(path, _)| path.clone())
+            .chain(
+                self.modified
+                    .keys()
+                    .filter(|path| !current.contains_key(*path))
+                    .cloned(),
+            )
+            .collect();
+        changed.sort();
+
+        self.modified = current;
+        changed
+    }
+}
+
+fn modified(path: &Path) -> Option<SystemTime> {
+    fs::metadata(path).and_then(|meta| meta.modified()).ok()
+}
+
+/// The files a watched day depends on: its source module, its input, its
+/// example inputs and the example answers. Example files are listed afresh
+/// on every call so new examples are picked up.
+pub fn watched_paths(
+    year: u32,
+    day: u32,
+    input: Option<&Path>,
+    examples_dir: &Path,
+) -> Vec<PathBuf> {
+    let mut paths = vec![scaffold::module_path(year, day)];
+    paths.extend(input.map(Path::to_path_buf));
+
+    if let Ok(found) = examples::discover(examples_dir) {
+        paths.extend(
+            found
+                .into_iter()
+                .filter(|example| example.day == day)
+                .map(|example| example.path),
+        );
+    }
+    paths.push(examples_dir.join(examples::ANSWERS_FILE));
+
+    paths
+}
+
+/// Rebuilds the crate with cargo, in the profile this binary was built with.
+/// Cargo prints any compile errors itself.
+pub fn rebuild() -> bool {
+    let cargo = env::var_os("CARGO").unwrap_or_else(|| "cargo".into());
+    let mut command = Command::new(cargo);
+    command.args(["build", "--quiet"]);
+    if !cfg!(debug_assertions) {
+        command.arg("--release");
+    }
+
+    command.status().is_ok_and(|status| status.success())
+}
+
+/// Replaces this process with a fresh start of the rebuilt binary, given the
+/// same arguments. Only returns, with the reason, if that fails.
+pub fn restart() -> String {
+    let mut args = env::args_os();
+    let Some(program) = args.next() else {
+        return "the program path is unknown".to_string();
+    };
+    let mut command = Command::new(program);
+    command.args(args);
+
+    #[cfg(unix)]
+    {
+        use std::os::unix::process::CommandExt;
+        command.exec().to_string()
+    }
+    #[cfg(not(unix))]
+    match command.status() {
+        Ok(status) => std::process::exit(status.code().unwrap_or(1)),
+        Err(err) => err.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::File;
+
+    #[test]
+    fn test_poll() {
+        let dir = std::env::temp_dir().join(format!("aoc-watch-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let input = dir.join("day01.txt");
+        let example = dir.join("day01-1.txt");
+        fs::write(&input, "1").unwrap();
+
+        let mut watcher = Watcher::new();
+        let paths = [input.clone(), example.clone()];
+        assert_eq!(watcher.poll(&paths), [example.clone(), input.clone()]);
+        assert!(watcher.poll(&paths).is_empty());
+
+        let later = SystemTime::now() + Duration::from_secs(60);
+        File::options()
+            .write(true)
+            .open(&input)
+            .unwrap()
+            .set_modified(later)
+            .unwrap();
+        assert_eq!(watcher.poll(&paths), &paths[..1]);
+
+        fs::write(&example, "2").unwrap();
+        assert_eq!(watcher.poll(&paths), &paths[1..]);
+        assert_eq!(watcher.poll(&paths[..1]), [example]);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_watched_paths() {
+        let dir = std::env::temp_dir().join(format!("aoc-watch-paths-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("day01-1.txt"), "").unwrap();
+        fs::write(dir.join("day02-1.txt"), "").unwrap();
+
+        let paths = watched_paths(2024, 1, Some(Path::new("inputs/2024/day01.txt")), &dir);
+        assert_eq!(
+            paths,
+            [
+                PathBuf::from("src/year2024/day01.rs"),
+                PathBuf::from("inputs/2024/day01.txt"),
+                dir.join("day01-1.txt"),
+                dir.join(examples::ANSWERS_FILE),
+            ]
+        );
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,103 @@
+use std::collections::HashMap;
+
+use crate::error::{ParseError, ParseResult};
+use crate::parsing;
+use crate::solution::{Answer, Solution};
+
+type Matrix = Vec<Vec<u32>>;
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input = Matrix;
+
+    fn parse(input: &str) -> ParseResult<Matrix> {
+        parse_input(input)
+    }
+
+    fn part1(columns: &Matrix) -> Answer {
+        sorted_sum_distances(columns.clone()).into()
+    }
+
+    fn part2(columns: &Matrix) -> Answer {
+        similarity(columns).into()
+    }
+}
+
+fn sorted_sum_distances(columns: Matrix) -> u32 {
+    let sorted_input: Matrix = columns
+        .into_iter()
+        .map(|mut inner| {
+            inner.sort();
+            inner
+        })
+        .collect();
+
+    sum_distances(sorted_input)
+}
+
+pub fn sum_distances(columns: Matrix) -> u32 {
+    columns[0]
+        .iter()
+        .zip(&columns[1])
+        .map(|(a, b)| a.abs_diff(*b))
+        .sum()
+}
+
+fn parse_input(input: &str) -> ParseResult<Matrix> {
+    let rows: Matrix = parsing::lines(input)
+        .map(|(index, line)| {
+            let row = parsing::numbers(index, line)?;
+
+            if row.len() != 2 {
+                return Err(ParseError::at_line(index, "Expected two location IDs"));
+            }
+
+            Ok(row)
+        })
+        .collect::<ParseResult<Matrix>>()?;
+
+    if rows.is_empty() {
+        return Ok(vec![Vec::new(), Vec::new()]);
+    }
+
+    Ok(transpose(rows))
+}
+
+fn transpose(rows: Matrix) -> Matrix {
+    if rows.is_empty() {
+        return Vec::new();
+    }
+    let num_columns = rows[0].len();
+    (0..num_columns)
+        .map(|col_idx| rows.iter().map(|row| row[col_idx]).collect())
+        .collect()
+}
+
+fn similarity(parsed_input: &Matrix) -> u32 {
+    let occurrence_lookup = count_occurrences(&parsed_input[1]);
+
+    parsed_input[0]
+        .iter()
+        .map(|&x| x * *occurrence_lookup.get(&x).unwrap_or(&0) as u32)
+        .sum()
+}
+
+fn count_occurrences(numbers: &[u32]) -> HashMap<u32, usize> {
+    let mut counts = HashMap::new();
+    for &number in numbers {
+        *counts.entry(number).or_insert(0) += 1;
+    }
+    counts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_invalid_input() {
+        let error = Day01::parse("3   4\n4   x3").err().unwrap();
+        assert_eq!((error.line, error.column), (2, 5));
+    }
+}
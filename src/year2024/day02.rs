@@ -1,23 +1,40 @@
+use crate::error::ParseResult;
+use crate::parsing;
+use crate::solution::{Answer, Solution};
+
 type Level = i32;
 type Report = Vec<Level>;
 type Reports = Vec<Report>;
 
-pub fn run(input: String) {
-    println!("Part 1: {}", part1(&input));
-    println!("Part 2: {}", part2(&input));
-}
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input = Reports;
+
+    fn parse(input: &str) -> ParseResult<Reports> {
+        parse_reports(input)
+    }
 
-pub fn parse_reports(input: &str) -> Reports {
-    input.lines().map(|line| parse_report(line)).collect()
+    fn part1(reports: &Reports) -> Answer {
+        count_safe(reports).into()
+    }
+
+    fn part2(reports: &Reports) -> Answer {
+        count_safe_with_dampener(reports).into()
+    }
 }
 
-pub fn parse_report(input: &str) -> Report {
-    input
-        .split_whitespace()
-        .map(|text| text.parse::<Level>().expect("Invalid number"))
+pub fn parse_reports(input: &str) -> ParseResult<Reports> {
+    parsing::lines(input)
+        .map(|(index, line)| parse_report(index, line))
         .collect()
 }
 
+/// Parses the report on `line`, whose 0-based index is used for errors.
+pub fn parse_report(line_index: usize, line: &str) -> ParseResult<Report> {
+    parsing::numbers(line_index, line)
+}
+
 #[derive(Copy, Clone, Debug, PartialEq)]
 pub enum Direction {
     Increasing,
@@ -33,7 +50,7 @@ pub fn is_safe(report: &Report) -> bool {
 
     for (index, level) in report.iter().enumerate().skip(1) {
         let previous_level = report[index - 1];
-        if direction == None && *level != previous_level {
+        if direction.is_none() && *level != previous_level {
             if *level > previous_level {
                 direction = Some(Direction::Increasing);
             } else {
@@ -57,22 +74,18 @@ pub fn check(first: Level, second: Level, direction: Direction) -> bool {
     let threshold = 3;
 
     match direction {
-        Direction::Increasing => return first < second && (first - second).abs() <= threshold,
-        Direction::Decreasing => return first > second && (first - second).abs() <= threshold,
+        Direction::Increasing => first < second && (first - second).abs() <= threshold,
+        Direction::Decreasing => first > second && (first - second).abs() <= threshold,
     }
 }
 
-pub fn part1(input: &str) -> u32 {
-    parse_reports(input)
-        .into_iter()
-        .map(|report| is_safe(&report))
-        .filter(|&safe| safe)
-        .count() as u32
+fn count_safe(reports: &Reports) -> u32 {
+    reports.iter().map(is_safe).filter(|&safe| safe).count() as u32
 }
 
-pub fn part2(input: &str) -> u32 {
-    parse_reports(input)
-        .into_iter()
+fn count_safe_with_dampener(reports: &Reports) -> u32 {
+    reports
+        .iter()
         .map(|report| {
             for index in 0..report.len() {
                 let new_report: Report = report
@@ -92,25 +105,3 @@ pub fn part2(input: &str) -> u32 {
         .filter(|&safe| safe)
         .count() as u32
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    const TEST_INPUT: &str = "7 6 4 2 1
-1 2 7 8 9
-9 7 6 2 1
-1 3 2 4 5
-8 6 4 4 1
-1 3 6 7 9";
-
-    #[test]
-    fn test_part1() {
-        assert_eq!(part1(&TEST_INPUT), 2);
-    }
-
-    #[test]
-    fn test_part2() {
-        assert_eq!(part2(&TEST_INPUT), 4);
-    }
-}
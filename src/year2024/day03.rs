@@ -1,14 +1,33 @@
+use std::sync::LazyLock;
+
 use regex::Regex;
 
-pub fn run(input: String) {
-    println!("Part 1: {}", solve_part1(&input));
-    println!("Part 2: {}", solve_part2(&input));
+use crate::error::ParseResult;
+use crate::solution::{Answer, Solution};
+
+static MUL: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"mul\((\d{1,3}),(\d{1,3})\)").unwrap());
+static DO_DONT: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"do\(\)|don't\(\)").unwrap());
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = String;
+
+    fn parse(input: &str) -> ParseResult<String> {
+        Ok(input.to_string())
+    }
+
+    fn part1(memory: &String) -> Answer {
+        solve_part1(memory).into()
+    }
+
+    fn part2(memory: &String) -> Answer {
+        solve_part2(memory).into()
+    }
 }
 
 pub fn solve(input: &str) -> u32 {
-    let re = Regex::new(r"mul\((\d{1,3}),(\d{1,3})\)").unwrap();
-
-    re.captures_iter(input)
+    MUL.captures_iter(input)
         .filter_map(|capture| {
             let x = capture[1].parse::<u32>().ok()?;
             let y = capture[2].parse::<u32>().ok()?;
@@ -22,14 +41,14 @@ pub fn solve_part1(input: &str) -> u32 {
 }
 
 fn get_do_dont_instruction_offsets(input: &str) -> Vec<(usize, usize)> {
-    let re = Regex::new(r"do\(\)|don't\(\)").unwrap();
-    re.find_iter(input)
+    DO_DONT
+        .find_iter(input)
         .map(|mat| (mat.start(), mat.end()))
         .collect()
 }
 
 fn get_valid_regions(
-    do_dont_instruction_offsets: &Vec<(usize, usize)>,
+    do_dont_instruction_offsets: &[(usize, usize)],
     input: &str,
 ) -> Vec<(usize, usize)> {
     if do_dont_instruction_offsets.is_empty() {
@@ -51,15 +70,14 @@ fn get_valid_regions(
         }
 
         let start = offset.1;
-        let mut end = start;
         let is_final_instruction = index == do_dont_instruction_offsets.len() - 1;
 
-        if is_final_instruction {
-            end = input.len();
+        let end = if is_final_instruction {
+            input.len()
         } else {
             let next_offset = do_dont_instruction_offsets[index + 1];
-            end = next_offset.0;
-        }
+            next_offset.0
+        };
 
         regions.push((start, end));
     }
@@ -78,23 +96,3 @@ pub fn solve_part2(input: &str) -> u32 {
 
     solve(&output)
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_part1() {
-        let test_input = "xmul(2,4)%&mul[3,7]!@^do_not_mul(5,5)+mul(32,64]then(mul(11,8)mul(8,5))";
-
-        assert_eq!(solve_part1(&test_input), 161);
-    }
-
-    #[test]
-    fn test_part2() {
-        let test_input =
-            "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))";
-
-        assert_eq!(solve_part2(&test_input), 48);
-    }
-}
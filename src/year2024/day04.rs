@@ -0,0 +1,111 @@
+use crate::error::ParseResult;
+use crate::grid::{Direction, Grid, Position};
+use crate::solution::{Answer, Solution};
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Character {
+    X,
+    M,
+    A,
+    S,
+}
+
+impl TryFrom<char> for Character {
+    type Error = &'static str;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            'X' => Ok(Character::X),
+            'M' => Ok(Character::M),
+            'A' => Ok(Character::A),
+            'S' => Ok(Character::S),
+            _ => Err("Invalid character, expected one of XMAS"),
+        }
+    }
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input = Grid<Character>;
+
+    fn parse(input: &str) -> ParseResult<Grid<Character>> {
+        Grid::parse(input, Character::try_from)
+    }
+
+    fn part1(grid: &Grid<Character>) -> Answer {
+        count_xmas(grid).into()
+    }
+
+    fn part2(grid: &Grid<Character>) -> Answer {
+        count_x_mas(grid).into()
+    }
+}
+
+fn count_xmas(grid: &Grid<Character>) -> u32 {
+    grid.positions()
+        .map(|position| {
+            Direction::ALL
+                .iter()
+                .filter(|&&direction| found_match_part1(grid, position, direction))
+                .count() as u32
+        })
+        .sum()
+}
+
+fn count_x_mas(grid: &Grid<Character>) -> u32 {
+    grid.positions()
+        .filter(|&position| found_match_part2(grid, position))
+        .count() as u32
+}
+
+fn check_character(
+    grid: &Grid<Character>,
+    position: Position,
+    character_to_match: Character,
+) -> bool {
+    grid.get(position) == Some(&character_to_match)
+}
+
+fn found_match_part1(grid: &Grid<Character>, position: Position, direction: Direction) -> bool {
+    if !check_character(grid, position, Character::X) {
+        return false;
+    }
+
+    if !check_character(grid, position.offset_by(direction, 1), Character::M) {
+        return false;
+    }
+
+    if !check_character(grid, position.offset_by(direction, 2), Character::A) {
+        return false;
+    }
+
+    if !check_character(grid, position.offset_by(direction, 3), Character::S) {
+        return false;
+    }
+
+    true
+}
+
+fn found_match_part2(grid: &Grid<Character>, position: Position) -> bool {
+    if !check_character(grid, position, Character::A) {
+        return false;
+    }
+
+    let top_left = position.step(Direction::UpLeft);
+    let top_right = position.step(Direction::UpRight);
+    let bottom_left = position.step(Direction::DownLeft);
+    let bottom_right = position.step(Direction::DownRight);
+
+    let left_diagonal_matches = check_character(grid, top_left, Character::M)
+        && check_character(grid, bottom_right, Character::S)
+        || check_character(grid, top_left, Character::S)
+            && check_character(grid, bottom_right, Character::M);
+
+    let right_diagonal_matches = check_character(grid, top_right, Character::M)
+        && check_character(grid, bottom_left, Character::S)
+        || check_character(grid, top_right, Character::S)
+            && check_character(grid, bottom_left, Character::M);
+
+    left_diagonal_matches && right_diagonal_matches
+}
@@ -0,0 +1,155 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::error::{ParseError, ParseResult};
+use crate::parsing;
+use crate::solution::{Answer, Solution};
+
+type Page = u8;
+type Rule = (Page, Page);
+type Update = Vec<Page>;
+type Rules = HashMap<Page, HashSet<Page>>;
+
+pub struct PrintQueue {
+    rules: Rules,
+    rules_vec: Vec<Rule>,
+    updates: Vec<Update>,
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input = PrintQueue;
+
+    fn parse(input: &str) -> ParseResult<PrintQueue> {
+        parse_input(input)
+    }
+
+    fn part1(queue: &PrintQueue) -> Answer {
+        sum_ordered_middle_pages(queue).into()
+    }
+
+    fn part2(queue: &PrintQueue) -> Answer {
+        sum_reordered_middle_pages(queue).into()
+    }
+}
+
+fn sum_ordered_middle_pages(queue: &PrintQueue) -> u32 {
+    queue
+        .updates
+        .iter()
+        .filter(|update| in_correct_order(update, &queue.rules))
+        .map(|update| get_middle_page(update) as u32)
+        .sum()
+}
+
+fn sum_reordered_middle_pages(queue: &PrintQueue) -> u32 {
+    queue
+        .updates
+        .iter()
+        .filter(|update| !in_correct_order(update, &queue.rules))
+        .map(|update| reorder(update, &queue.rules_vec))
+        .map(|update| get_middle_page(&update) as u32)
+        .sum()
+}
+
+fn parse_input(input: &str) -> ParseResult<PrintQueue> {
+    let blocks = parsing::blocks(input);
+    let (rule_lines, update_lines) = match blocks.as_slice() {
+        [] => (&[][..], &[][..]),
+        [rules] => (rules.as_slice(), &[][..]),
+        [rules, updates] => (rules.as_slice(), updates.as_slice()),
+        [_, _, extra, ..] => {
+            return Err(ParseError::at_line(
+                extra[0].0,
+                "Expected only rules and updates, separated by one blank line",
+            ))
+        }
+    };
+
+    let rules_vec = rule_lines
+        .iter()
+        .map(|&(index, line)| parse_rule(index, line))
+        .collect::<ParseResult<Vec<Rule>>>()?;
+    let updates = update_lines
+        .iter()
+        .map(|&(index, line)| parsing::separated(index, line, ','))
+        .collect::<ParseResult<Vec<Update>>>()?;
+
+    Ok(PrintQueue {
+        rules: group_rules(&rules_vec),
+        rules_vec,
+        updates,
+    })
+}
+
+fn parse_rule(index: usize, line: &str) -> ParseResult<Rule> {
+    match parsing::separated(index, line, '|')?[..] {
+        [before, after] => Ok((before, after)),
+        _ => Err(ParseError::at_line(index, "Expected a rule like 47|53")),
+    }
+}
+
+fn group_rules(rules_vec: &[Rule]) -> Rules {
+    rules_vec.iter().fold(HashMap::new(), |mut map, &(x, y)| {
+        map.entry(x).or_insert_with(HashSet::new).insert(y);
+        map
+    })
+}
+
+fn reorder(update: &Update, rules_vec: &[Rule]) -> Update {
+    let mut reordered = update.clone();
+
+    loop {
+        let mut changed = false;
+
+        for rule in rules_vec {
+            if let (Some(first), Some(second)) = (
+                reordered.iter().position(|&x| x == rule.0),
+                reordered.iter().position(|&x| x == rule.1),
+            ) {
+                if first > second {
+                    reordered.swap(first, second);
+                    changed = true;
+                }
+            }
+        }
+
+        if !changed {
+            break;
+        }
+    }
+
+    reordered
+}
+
+fn has_rule(rules: &Rules, first_number: Page, second_number: Page) -> bool {
+    rules
+        .get(&first_number)
+        .is_some_and(|set| set.contains(&second_number))
+}
+
+fn get_middle_page(update: &Update) -> Page {
+    update[(update.len() - 1) / 2]
+}
+
+fn in_correct_order(update: &Update, rules: &Rules) -> bool {
+    for i in 0..update.len() {
+        for j in i..update.len() {
+            if i != j && !has_rule(rules, update[i], update[j]) {
+                return false;
+            }
+        }
+    }
+    true
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_invalid_input() {
+        let error = Day05::parse("47|53\n97-13\n\n75,47").err().unwrap();
+        assert_eq!(error.line, 2);
+    }
+}
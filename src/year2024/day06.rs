@@ -0,0 +1,224 @@
+use std::fmt;
+
+use crate::error::{ParseError, ParseResult};
+use crate::grid::{Direction, Grid, Position};
+use crate::parallel;
+use crate::solution::{Answer, Solution};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GridCell {
+    has_obstacle: bool,
+    exit_bitset: u8,
+    last_exited: Option<Direction>,
+}
+
+impl GridCell {
+    fn exit_from(&mut self, direction: Direction) {
+        self.last_exited = Some(direction);
+        self.exit_bitset |= 1 << direction.bit();
+    }
+
+    fn has_exited(&mut self, direction: Direction) -> bool {
+        self.exit_bitset & 1 << direction.bit() != 0
+    }
+}
+
+impl TryFrom<char> for GridCell {
+    type Error = &'static str;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            '.' => Ok(GridCell {
+                has_obstacle: false,
+                exit_bitset: 0,
+                last_exited: None,
+            }),
+            '#' => Ok(GridCell {
+                has_obstacle: true,
+                exit_bitset: 0,
+                last_exited: None,
+            }),
+            '^' => Ok(GridCell {
+                has_obstacle: false,
+                exit_bitset: Direction::Up.bit(),
+                last_exited: Some(Direction::Up),
+            }),
+            _ => Err("Invalid map cell, expected ., # or ^"),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Lab {
+    guard: Option<(Position, Direction)>,
+    cells: Grid<GridCell>,
+}
+
+impl fmt::Display for Lab {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (position, cell) in self.cells.iter() {
+            match self.guard {
+                // The guard only turns between the cardinal directions, so
+                // `?` never shows up unless the lab itself is broken.
+                Some((guard_pos, guard_dir)) if guard_pos == position => {
+                    write!(f, "{}", guard_dir.arrow().unwrap_or('?'))?
+                }
+                _ if cell.has_obstacle => write!(f, "#")?,
+                _ => match cell.last_exited {
+                    None => write!(f, ".")?,
+                    Some(Direction::Up) => write!(f, "↑")?,
+                    Some(Direction::Down) => write!(f, "↓")?,
+                    Some(Direction::Left) => write!(f, "←")?,
+                    Some(_) => write!(f, "→")?,
+                },
+            }
+
+            if position.x as usize == self.cells.width() - 1 {
+                writeln!(f)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Lab {
+    fn get_cell(&mut self, position: Position) -> Option<&mut GridCell> {
+        self.cells.get_mut(position)
+    }
+
+    pub fn step(&mut self) {
+        let (current_position, current_direction) = self.guard.unwrap();
+        let new_position = current_position.step(current_direction);
+
+        if let Some(new_cell) = self.get_cell(new_position) {
+            if new_cell.has_obstacle {
+                self.guard = Some((current_position, current_direction.turn_right()));
+            } else {
+                self.cells[current_position].exit_from(current_direction);
+                self.guard = Some((new_position, current_direction));
+            }
+        } else {
+            self.cells[current_position].exit_from(current_direction);
+
+            self.guard = None
+        }
+    }
+}
+
+fn parse_input(input: &str) -> ParseResult<Lab> {
+    let cells = Grid::parse(input, GridCell::try_from)?;
+    let guard = cells
+        .find(|cell| cell.last_exited.is_some())
+        .ok_or_else(|| ParseError::new(1, 1, "The map has no guard (^)"))?;
+
+    Ok(Lab {
+        guard: Some((guard, Direction::Up)),
+        cells,
+    })
+}
+
+fn count_visited(lab: &Lab) -> u32 {
+    lab.cells
+        .iter()
+        .filter(|(_, cell)| cell.last_exited.is_some())
+        .count() as u32
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input = Lab;
+
+    fn parse(input: &str) -> ParseResult<Lab> {
+        parse_input(input)
+    }
+
+    fn part1(lab: &Lab) -> Answer {
+        count_guard_visits(lab).into()
+    }
+
+    fn part2(lab: &Lab) -> Answer {
+        count_looping_obstacles(lab).into()
+    }
+}
+
+fn count_guard_visits(original_lab: &Lab) -> u32 {
+    // A guard caught in a loop never leaves, but by the time the loop is
+    // found it has visited every cell it ever will.
+    let mut lab = original_lab.clone();
+    has_loop(&mut lab);
+
+    count_visited(&lab)
+}
+
+fn has_loop(lab: &mut Lab) -> bool {
+    loop {
+        lab.step();
+
+        if let Some(guard) = lab.guard {
+            if let Some(cell) = lab.get_cell(guard.0) {
+                if cell.has_exited(guard.1) {
+                    return true;
+                }
+            }
+        } else {
+            return false;
+        }
+    }
+}
+
+/// Tries an obstacle on every cell of the guard's original route, the only
+/// cells where one can change where the guard goes. If that route already
+/// loops, an obstacle anywhere else keeps it looping, so every free cell is
+/// tried.
+fn count_looping_obstacles(original_lab: &Lab) -> u32 {
+    let start = original_lab.guard.unwrap().0;
+    let mut walked = original_lab.clone();
+    let already_loops = has_loop(&mut walked);
+
+    let candidates: Vec<Position> = walked
+        .cells
+        .iter()
+        .filter(|&(position, cell)| {
+            position != start && !cell.has_obstacle && (already_loops || cell.last_exited.is_some())
+        })
+        .map(|(position, _)| position)
+        .collect();
+
+    parallel::count(&candidates, |&position| {
+        let mut lab = original_lab.clone();
+        lab.cells[position].has_obstacle = true;
+        has_loop(&mut lab)
+    }) as u32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_line() {
+        let input: &str = ".#.....#..
+#........#
+.^......#.
+";
+        let mut lab = parse_input(input).unwrap();
+        assert!(has_loop(&mut lab));
+    }
+
+    #[test]
+    fn test_route_already_loops() {
+        let lab = Day06::parse(".#..\n...#\n#^..\n..#.").unwrap();
+
+        assert_eq!(Day06::part1(&lab), 4);
+        assert_eq!(Day06::part2(&lab), 8);
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        let error = Day06::parse("..#\n.?^").err().unwrap();
+        assert_eq!((error.line, error.column), (2, 2));
+        assert!(Day06::parse("..#\n...").is_err());
+    }
+}
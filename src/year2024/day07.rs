@@ -0,0 +1,117 @@
+use crate::error::{ParseError, ParseResult};
+use crate::parallel;
+use crate::parsing;
+use crate::solution::{Answer, Solution};
+
+type Number = u64;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Equation {
+    result: Number,
+    operands: Vec<Number>,
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Operator {
+    Add,
+    Multiply,
+    Concat,
+}
+
+impl Operator {
+    fn all() -> [Operator; 3] {
+        [Operator::Add, Operator::Multiply, Operator::Concat]
+    }
+}
+
+fn parse_equation(line_index: usize, line: &str) -> ParseResult<Equation> {
+    let (result, operands) = parsing::key_value(line_index, line, ":")?;
+    let result = parsing::number(line_index, line, result)?;
+    let operands = operands
+        .split_whitespace()
+        .map(|token| parsing::number(line_index, line, token))
+        .collect::<ParseResult<Vec<Number>>>()?;
+
+    if operands.is_empty() {
+        return Err(ParseError::at_line(
+            line_index,
+            "Expected an equation like 190: 10 19",
+        ));
+    }
+
+    Ok(Equation { result, operands })
+}
+
+fn parse_input(input: &str) -> ParseResult<Vec<Equation>> {
+    parsing::lines(input)
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(line_index, line)| parse_equation(line_index, line))
+        .collect()
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input = Vec<Equation>;
+
+    fn parse(input: &str) -> ParseResult<Vec<Equation>> {
+        parse_input(input)
+    }
+
+    fn part1(equations: &Vec<Equation>) -> Answer {
+        calibration_result(equations, false).into()
+    }
+
+    fn part2(equations: &Vec<Equation>) -> Answer {
+        calibration_result(equations, true).into()
+    }
+}
+
+pub fn perform_operation(operator: Operator, operand1: Number, operand2: Number) -> Number {
+    match operator {
+        Operator::Add => operand1 + operand2,
+        Operator::Multiply => operand1 * operand2,
+        Operator::Concat => {
+            let operand2_num_digits = (operand2 as f64).log10().floor() as u64 + 1;
+            operand1 * 10u64.pow(operand2_num_digits as u32) + operand2
+        }
+    }
+}
+
+pub fn could_be_true(equation: &Equation, allow_concat: bool) -> bool {
+    if equation.operands.len() == 1 {
+        return equation.operands[0] == equation.result;
+    }
+
+    if equation.operands[0] > equation.result {
+        return false;
+    }
+
+    for operator in Operator::all() {
+        if !allow_concat && operator == Operator::Concat {
+            continue;
+        }
+
+        let result = perform_operation(operator, equation.operands[0], equation.operands[1]);
+        let mut cloned_eq = equation.clone();
+        cloned_eq.operands.splice(0..2, std::iter::once(result));
+
+        if could_be_true(&cloned_eq, allow_concat) {
+            return true;
+        }
+    }
+
+    false
+}
+
+fn calibration_result(equations: &[Equation], allow_concat: bool) -> Number {
+    parallel::map(equations, |equation| {
+        if could_be_true(equation, allow_concat) {
+            equation.result
+        } else {
+            0
+        }
+    })
+    .into_iter()
+    .sum::<Number>()
+}
@@ -0,0 +1,107 @@
+use std::collections::{HashMap, HashSet};
+
+use crate::error::ParseResult;
+use crate::grid::{Grid, Position};
+use crate::solution::{Answer, Solution};
+
+pub struct AntennaMap {
+    antennas: HashMap<char, HashSet<Position>>,
+    area: Grid<char>,
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input = AntennaMap;
+
+    fn parse(input: &str) -> ParseResult<AntennaMap> {
+        parse_input(input)
+    }
+
+    fn part1(map: &AntennaMap) -> Answer {
+        count_antinodes(map).into()
+    }
+
+    fn part2(map: &AntennaMap) -> Answer {
+        count_resonant_antinodes(map).into()
+    }
+}
+
+fn parse_input(input: &str) -> ParseResult<AntennaMap> {
+    let area = Grid::parse(input, |ch| {
+        if ch == '.' || ch.is_ascii_alphanumeric() {
+            Ok(ch)
+        } else {
+            Err("Invalid antenna, expected a letter or digit")
+        }
+    })?;
+
+    let mut antennas = HashMap::new();
+    for (position, &ch) in area.iter().filter(|(_, &ch)| ch != '.') {
+        antennas
+            .entry(ch)
+            .or_insert_with(HashSet::new)
+            .insert(position);
+    }
+
+    Ok(AntennaMap { antennas, area })
+}
+
+fn count_antinodes(map: &AntennaMap) -> usize {
+    let mut antinodes: HashSet<Position> = HashSet::new();
+
+    for positions_hashset in map.antennas.values() {
+        let positions: Vec<_> = positions_hashset.iter().collect();
+
+        for i in 0..positions.len() {
+            for j in (i + 1)..positions.len() {
+                let antenna1 = positions[i];
+                let antenna2 = positions[j];
+                let offset = *antenna2 - *antenna1;
+
+                for antinode in [*antenna1 - offset, *antenna2 + offset] {
+                    if map.area.contains(antinode) {
+                        antinodes.insert(antinode);
+                    }
+                }
+            }
+        }
+    }
+
+    antinodes.len()
+}
+
+fn collect_positions_in_line(
+    start: Position,
+    offset: Position,
+    area: &Grid<char>,
+    antinodes: &mut HashSet<Position>,
+) {
+    let mut current_position = start;
+
+    while area.contains(current_position) {
+        antinodes.insert(current_position);
+        current_position = current_position + offset;
+    }
+}
+
+fn count_resonant_antinodes(map: &AntennaMap) -> usize {
+    let mut antinodes: HashSet<Position> = HashSet::new();
+
+    for positions_hashset in map.antennas.values() {
+        let positions: Vec<_> = positions_hashset.iter().collect();
+
+        for i in 0..positions.len() {
+            for j in (i + 1)..positions.len() {
+                let antenna1 = positions[i];
+                let antenna2 = positions[j];
+                let offset = *antenna2 - *antenna1;
+
+                collect_positions_in_line(*antenna2, offset, &map.area, &mut antinodes);
+                collect_positions_in_line(*antenna2, -offset, &map.area, &mut antinodes);
+            }
+        }
+    }
+
+    antinodes.len()
+}
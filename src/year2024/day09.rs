@@ -1,5 +1,9 @@
 use std::{fmt, iter};
 
+use crate::error::ParseResult;
+use crate::parsing;
+use crate::solution::{Answer, Solution};
+
 type FileID = u32;
 type Blocks = Vec<Block>;
 
@@ -24,20 +28,36 @@ impl fmt::Display for Block {
     }
 }
 
-pub fn run(input: String) {
-    println!("Part 1: {}", solve(&input, SearchMode::SingleBlock));
-    println!("Part 2: {}", solve(&input, SearchMode::Exhaustive));
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input = Blocks;
+
+    fn parse(input: &str) -> ParseResult<Blocks> {
+        parse_input(input)
+    }
+
+    fn part1(blocks: &Blocks) -> Answer {
+        compact(blocks.clone(), SearchMode::SingleBlock).into()
+    }
+
+    fn part2(blocks: &Blocks) -> Answer {
+        compact(blocks.clone(), SearchMode::Exhaustive).into()
+    }
 }
 
-fn parse_input(input: &str) -> Blocks {
+fn parse_input(input: &str) -> ParseResult<Blocks> {
     let mut empty_disk_entry = false;
     let mut disk_entries: Blocks = Vec::new();
     let mut file_id = 0;
 
-    for character in input.chars() {
-        let data_size = character.to_digit(10).unwrap();
+    let sizes = parsing::lines(input)
+        .map(|(line_index, line)| parsing::digits(line_index, line))
+        .collect::<ParseResult<Vec<_>>>()?;
+
+    for data_size in sizes.into_iter().flatten() {
         if empty_disk_entry {
-            disk_entries.extend(iter::repeat(Block::Empty).take(data_size as usize));
+            disk_entries.extend(iter::repeat_n(Block::Empty, data_size as usize));
         } else {
             for _ in 0..data_size {
                 disk_entries.push(Block::File(file_id));
@@ -49,11 +69,11 @@ fn parse_input(input: &str) -> Blocks {
         empty_disk_entry = !empty_disk_entry;
     }
 
-    disk_entries
+    Ok(disk_entries)
 }
 
 pub fn forwards_search_for_first_contiguous_empty(
-    blocks: &Blocks,
+    blocks: &[Block],
     size: usize,
     until_index: usize,
 ) -> Option<(usize, usize)> {
@@ -84,7 +104,7 @@ pub fn forwards_search_for_first_contiguous_empty(
 }
 
 pub fn backwards_search_for_first_contiguous_nonempty(
-    blocks: &Blocks,
+    blocks: &[Block],
     before_index: usize,
     search_mode: SearchMode,
 ) -> Option<(usize, usize)> {
@@ -95,14 +115,12 @@ pub fn backwards_search_for_first_contiguous_nonempty(
     }
 
     let mut current_file_id: Option<FileID> = None;
-    let mut start_index: Option<usize> = None;
+    let start_index: Option<usize>;
     let mut end_index: Option<usize> = None;
 
     loop {
         if (index as i32) - 1 < 0 {
-            if current_file_id == None {
-                return None;
-            }
+            current_file_id?;
 
             return Some((0, end_index.unwrap()));
         }
@@ -120,11 +138,11 @@ pub fn backwards_search_for_first_contiguous_nonempty(
                 }
             }
 
-            if !current_file_id.is_none() && current_file_id == Some(file_id) {
+            if current_file_id == Some(file_id) {
                 continue;
             }
 
-            if !current_file_id.is_none() && current_file_id != Some(file_id) {
+            if current_file_id.is_some() {
                 start_index = Some(index + 1);
                 break;
             }
@@ -142,10 +160,10 @@ pub fn backwards_search_for_first_contiguous_nonempty(
         return None;
     }
 
-    return Some((start_index.unwrap(), end_index.unwrap()));
+    Some((start_index.unwrap(), end_index.unwrap()))
 }
 
-pub fn checksum(blocks: &Blocks) -> u64 {
+pub fn checksum(blocks: &[Block]) -> u64 {
     blocks
         .iter()
         .enumerate()
@@ -156,9 +174,7 @@ pub fn checksum(blocks: &Blocks) -> u64 {
         .sum()
 }
 
-pub fn solve(input: &str, search_mode: SearchMode) -> u64 {
-    let mut blocks = parse_input(input);
-
+fn compact(mut blocks: Blocks, search_mode: SearchMode) -> u64 {
     let mut file_region: Option<(usize, usize)> = None;
 
     loop {
@@ -188,20 +204,3 @@ pub fn solve(input: &str, search_mode: SearchMode) -> u64 {
 
     checksum(&blocks)
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    const TEST_INPUT: &str = "2333133121414131402";
-
-    #[test]
-    fn test_part1() {
-        assert_eq!(solve(TEST_INPUT, SearchMode::SingleBlock), 1928);
-    }
-
-    #[test]
-    fn test_part2() {
-        assert_eq!(solve(TEST_INPUT, SearchMode::Exhaustive), 2858);
-    }
-}
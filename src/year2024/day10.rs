@@ -0,0 +1,97 @@
+use crate::error::ParseResult;
+use crate::grid::{Direction, Grid, Position};
+use crate::parsing;
+use crate::search;
+use crate::solution::{Answer, Solution};
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub struct Cell {
+    value: u8,
+    incline_bitset: u8,
+}
+
+impl Cell {
+    fn can_climb_to(&self, direction: Direction) -> bool {
+        (self.incline_bitset & (1 << direction.bit())) != 0
+    }
+
+    fn enable_climb_to(&mut self, direction: Direction) {
+        self.incline_bitset |= 1 << direction.bit();
+    }
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Grid<Cell>;
+
+    fn parse(input: &str) -> ParseResult<Grid<Cell>> {
+        parse_input(input)
+    }
+
+    fn part1(grid: &Grid<Cell>) -> Answer {
+        sum_over_trailheads(grid, score).into()
+    }
+
+    fn part2(grid: &Grid<Cell>) -> Answer {
+        sum_over_trailheads(grid, rating).into()
+    }
+}
+
+fn parse_input(input: &str) -> ParseResult<Grid<Cell>> {
+    let mut grid = parsing::digit_grid(input)?.map(|&value| Cell {
+        value,
+        incline_bitset: 0,
+    });
+
+    for position in grid.positions() {
+        let mut cell = grid[position];
+
+        for direction in Direction::CARDINAL {
+            if let Some(new_cell) = grid.get(position.step(direction)) {
+                if new_cell.value == cell.value + 1 {
+                    cell.enable_climb_to(direction);
+                }
+            }
+        }
+
+        grid[position] = cell;
+    }
+
+    Ok(grid)
+}
+
+fn climbable_neighbors(grid: &Grid<Cell>, position: Position) -> impl Iterator<Item = Position> {
+    let cell = grid[position];
+    Direction::CARDINAL
+        .into_iter()
+        .filter(move |&direction| cell.can_climb_to(direction))
+        .map(move |direction| position.step(direction))
+}
+
+/// The number of distinct summits reachable from `trailhead`.
+fn score(grid: &Grid<Cell>, trailhead: Position) -> u32 {
+    search::bfs([trailhead], |position| climbable_neighbors(grid, position))
+        .order
+        .into_iter()
+        .filter(|&position| grid[position].value == 9)
+        .count() as u32
+}
+
+/// The number of distinct hiking trails starting at `position`.
+fn rating(grid: &Grid<Cell>, position: Position) -> u32 {
+    if grid[position].value == 9 {
+        return 1;
+    }
+
+    climbable_neighbors(grid, position)
+        .map(|next| rating(grid, next))
+        .sum()
+}
+
+fn sum_over_trailheads(grid: &Grid<Cell>, measure: fn(&Grid<Cell>, Position) -> u32) -> u32 {
+    grid.iter()
+        .filter(|(_, cell)| cell.value == 0)
+        .map(|(position, _)| measure(grid, position))
+        .sum()
+}
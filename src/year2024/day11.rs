@@ -1,17 +1,35 @@
 use std::collections::HashMap;
 
-pub fn run(input: String) {
-    println!("25: {}", solve_recursive(&input, 25));
-    println!("75: {}", solve_recursive(&input, 75));
-}
+use crate::error::ParseResult;
+use crate::parsing;
+use crate::solution::{Answer, Solution};
 
 type Number = u64;
 
-fn parse_input(input: &str) -> Vec<Number> {
-    input
-        .split_whitespace()
-        .filter_map(|s| s.parse::<Number>().ok())
-        .collect()
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Vec<Number>;
+
+    fn parse(input: &str) -> ParseResult<Vec<Number>> {
+        parse_input(input)
+    }
+
+    fn part1(stones: &Vec<Number>) -> Answer {
+        count_stones_after(stones, 25).into()
+    }
+
+    fn part2(stones: &Vec<Number>) -> Answer {
+        count_stones_after(stones, 75).into()
+    }
+}
+
+fn parse_input(input: &str) -> ParseResult<Vec<Number>> {
+    let lines = parsing::lines(input)
+        .map(|(line_index, line)| parsing::numbers(line_index, line))
+        .collect::<ParseResult<Vec<Vec<Number>>>>()?;
+
+    Ok(lines.concat())
 }
 
 fn split_number(num: Number) -> (Number, Number) {
@@ -32,7 +50,7 @@ fn transform(val: Number) -> Vec<Number> {
 
     let num_digits = (val as f64).log10() as Number + 1;
 
-    if num_digits % 2 == 0 {
+    if num_digits.is_multiple_of(2) {
         let (first_half, second_half) = split_number(val);
 
         return vec![first_half, second_half];
@@ -42,7 +60,7 @@ fn transform(val: Number) -> Vec<Number> {
 }
 
 fn count_stones(
-    vec: &Vec<Number>,
+    vec: &[Number],
     blink_count: u32,
     max_blink_count: u32,
 
@@ -68,20 +86,7 @@ fn count_stones(
         .sum()
 }
 
-pub fn solve_recursive(input: &str, blinks: u32) -> u64 {
-    let stones = parse_input(input);
+fn count_stones_after(stones: &[Number], blinks: u32) -> u64 {
     let mut cache = HashMap::new();
-    count_stones(&stones, 0, blinks, &mut cache)
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    const TEST_INPUT: &str = "125 17";
-
-    #[test]
-    fn test_recursive() {
-        assert_eq!(solve_recursive(TEST_INPUT, 25), 55312);
-    }
+    count_stones(stones, 0, blinks, &mut cache)
 }
@@ -0,0 +1,177 @@
+use std::collections::HashSet;
+
+use crate::error::ParseResult;
+use crate::grid::{Direction, Grid, Position};
+use crate::search;
+use crate::solution::{Answer, Solution};
+
+#[derive(Debug)]
+struct Region {
+    positions: HashSet<Position>,
+}
+
+impl Region {
+    fn perimeter(&self) -> u32 {
+        self.positions
+            .iter()
+            .flat_map(|&position| Direction::CARDINAL.map(|direction| position.step(direction)))
+            .filter(|neighbor| !self.positions.contains(neighbor))
+            .count() as u32
+    }
+
+    fn area(&self) -> u32 {
+        self.positions.len() as u32
+    }
+
+    fn count_corners(&self) -> u32 {
+        self.positions
+            .iter()
+            .map(|position| self.count_corners_for(position))
+            .sum()
+    }
+
+    fn neighbours_count(&self, position: &Position, directions: &[Direction]) -> u32 {
+        directions
+            .iter()
+            .filter(|&&direction| self.positions.contains(&position.step(direction)))
+            .count() as u32
+    }
+
+    fn count_corners_for(&self, position: &Position) -> u32 {
+        let adjacent_count = self.neighbours_count(position, &Direction::CARDINAL);
+
+        if adjacent_count == 0 {
+            return 4;
+        }
+
+        if adjacent_count == 1 {
+            return 2;
+        }
+
+        if adjacent_count == 4 {
+            return 4 - self.neighbours_count(position, &Direction::DIAGONAL);
+        }
+
+        if adjacent_count == 2 {
+            if self.neighbours_count(position, &[Direction::Left, Direction::Right]) == 2
+                || self.neighbours_count(position, &[Direction::Up, Direction::Down]) == 2
+            {
+                return 0;
+            }
+
+            if self.neighbours_count(position, &[Direction::Left, Direction::Up]) == 2 {
+                return 2 - self.neighbours_count(position, &[Direction::UpLeft]);
+            }
+
+            if self.neighbours_count(position, &[Direction::Up, Direction::Right]) == 2 {
+                return 2 - self.neighbours_count(position, &[Direction::UpRight]);
+            }
+
+            if self.neighbours_count(position, &[Direction::Right, Direction::Down]) == 2 {
+                return 2 - self.neighbours_count(position, &[Direction::DownRight]);
+            }
+
+            if self.neighbours_count(position, &[Direction::Down, Direction::Left]) == 2 {
+                return 2 - self.neighbours_count(position, &[Direction::DownLeft]);
+            }
+        }
+
+        if adjacent_count == 3 {
+            if !self.positions.contains(&position.step(Direction::Left)) {
+                return 2 - self
+                    .neighbours_count(position, &[Direction::UpRight, Direction::DownRight]);
+            }
+
+            if !self.positions.contains(&position.step(Direction::Up)) {
+                return 2 - self
+                    .neighbours_count(position, &[Direction::DownLeft, Direction::DownRight]);
+            }
+
+            if !self.positions.contains(&position.step(Direction::Right)) {
+                return 2 - self
+                    .neighbours_count(position, &[Direction::UpLeft, Direction::DownLeft]);
+            }
+
+            if !self.positions.contains(&position.step(Direction::Down)) {
+                return 2 - self
+                    .neighbours_count(position, &[Direction::UpLeft, Direction::UpRight]);
+            }
+        }
+
+        0
+    }
+}
+
+fn form_regions(grid: &Grid<char>) -> Vec<Region> {
+    let mut visited: HashSet<Position> = HashSet::new();
+    let mut regions = Vec::new();
+
+    for (position, &letter) in grid.iter() {
+        if visited.contains(&position) {
+            continue;
+        }
+
+        let region = search::bfs([position], |current| {
+            grid.neighbors4(current)
+                .filter(move |&neighbor| grid[neighbor] == letter)
+        });
+        let positions: HashSet<Position> = region.order.into_iter().collect();
+
+        visited.extend(&positions);
+        regions.push(Region { positions });
+    }
+
+    regions
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Grid<char>;
+
+    fn parse(input: &str) -> ParseResult<Grid<char>> {
+        Grid::parse_chars(input)
+    }
+
+    fn part1(grid: &Grid<char>) -> Answer {
+        fencing_price(grid).into()
+    }
+
+    fn part2(grid: &Grid<char>) -> Answer {
+        bulk_fencing_price(grid).into()
+    }
+}
+
+fn fencing_price(grid: &Grid<char>) -> u32 {
+    let regions = form_regions(grid);
+    regions
+        .iter()
+        .map(|region| region.perimeter() * region.area())
+        .sum()
+}
+
+fn bulk_fencing_price(grid: &Grid<char>) -> u32 {
+    let regions = form_regions(grid);
+    regions
+        .iter()
+        .map(|region| region.count_corners() * region.area())
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_count_corners() {
+        let input = "..XXX
+XX.XX
+XXXX.";
+
+        let grid = Grid::parse_chars(input).unwrap();
+        let regions = form_regions(&grid);
+        let corners: u32 = regions.iter().map(|region| region.count_corners()).sum();
+
+        assert_eq!(corners, 24);
+    }
+}
@@ -0,0 +1,144 @@
+use crate::error::{ParseError, ParseResult};
+use crate::parsing;
+use crate::solution::{Answer, Solution};
+
+const UNIT_CONVERSION_OFFSET: i64 = 10_000_000_000_000;
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, PartialOrd)]
+struct Position {
+    x: i64,
+    y: i64,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Machine {
+    offset_a: Position,
+    offset_b: Position,
+    target: Position,
+}
+
+impl Machine {
+    fn with_unit_conversion(&self) -> Machine {
+        Machine {
+            target: Position {
+                x: self.target.x + UNIT_CONVERSION_OFFSET,
+                y: self.target.y + UNIT_CONVERSION_OFFSET,
+            },
+            ..*self
+        }
+    }
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = Vec<Machine>;
+
+    fn parse(input: &str) -> ParseResult<Vec<Machine>> {
+        parse_input(input)
+    }
+
+    fn part1(machines: &Vec<Machine>) -> Answer {
+        total_tickets(machines).into()
+    }
+
+    fn part2(machines: &Vec<Machine>) -> Answer {
+        total_converted_tickets(machines).into()
+    }
+}
+
+fn parse_position(line_index: usize, line: &str, label: &str) -> ParseResult<Position> {
+    if !line.trim_start().starts_with(label) {
+        return Err(ParseError::at_line(
+            line_index,
+            format!("Expected a line starting with {:?}", label),
+        ));
+    }
+
+    match parsing::integers(line_index, line)?[..] {
+        [x, y] => Ok(Position { x, y }),
+        _ => Err(ParseError::at_line(
+            line_index,
+            "Expected exactly two coordinates",
+        )),
+    }
+}
+
+fn parse_machine(block: &[(usize, &str)]) -> ParseResult<Machine> {
+    match *block {
+        [(a_index, a), (b_index, b), (prize_index, prize)] => Ok(Machine {
+            offset_a: parse_position(a_index, a, "Button A:")?,
+            offset_b: parse_position(b_index, b, "Button B:")?,
+            target: parse_position(prize_index, prize, "Prize:")?,
+        }),
+        _ => Err(ParseError::at_line(
+            block[0].0,
+            "Expected a machine with two buttons and a prize",
+        )),
+    }
+}
+
+fn parse_input(input: &str) -> ParseResult<Vec<Machine>> {
+    parsing::blocks(input)
+        .iter()
+        .map(|block| parse_machine(block))
+        .collect()
+}
+
+/// Solves the two button equations exactly with Cramer's rule. Floating point
+/// loses too much precision once the unit conversion offset is applied.
+pub fn press_counts(machine: &Machine) -> Option<(i64, i64)> {
+    let (a, b, target) = (machine.offset_a, machine.offset_b, machine.target);
+    let determinant = a.x * b.y - a.y * b.x;
+
+    if determinant == 0 {
+        return None;
+    }
+
+    let a_numerator = target.x * b.y - target.y * b.x;
+    let b_numerator = a.x * target.y - a.y * target.x;
+
+    if a_numerator % determinant != 0 || b_numerator % determinant != 0 {
+        return None;
+    }
+
+    let num_a = a_numerator / determinant;
+    let num_b = b_numerator / determinant;
+
+    if num_a < 0 || num_b < 0 {
+        return None;
+    }
+
+    Some((num_a, num_b))
+}
+
+pub fn min_tickets(machine: &Machine) -> Option<u64> {
+    let (num_a, num_b) = press_counts(machine)?;
+
+    let num_tickets_a = num_a as u64 * 3;
+    let num_tickets_b = num_b as u64;
+    Some(num_tickets_a + num_tickets_b)
+}
+
+fn total_tickets(machines: &[Machine]) -> u64 {
+    machines.iter().filter_map(min_tickets).sum()
+}
+
+fn total_converted_tickets(machines: &[Machine]) -> u64 {
+    let converted: Vec<Machine> = machines.iter().map(Machine::with_unit_conversion).collect();
+
+    total_tickets(&converted)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_invalid_input() {
+        let error = Day13::parse("Button A: X+94, Y+34\nButton B: X+22\nPrize: X=8400, Y=5400")
+            .err()
+            .unwrap();
+        assert_eq!((error.line, error.column), (2, 1));
+    }
+}
@@ -0,0 +1,309 @@
+use crate::error::{ParseError, ParseResult};
+use crate::grid::Position;
+use crate::parsing;
+use crate::solution::{Answer, Settings, Solution};
+
+/// The puzzle's example uses a much smaller arena than the real input.
+const EXAMPLE_ARENA: Arena = Arena {
+    width: 11,
+    height: 7,
+};
+const REAL_ARENA: Arena = Arena {
+    width: 101,
+    height: 103,
+};
+
+const SAFETY_SECONDS: i32 = 100;
+
+/// The floor the robots move on. Robots walking off one edge reappear on
+/// the opposite one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Arena {
+    width: i32,
+    height: i32,
+}
+
+impl Arena {
+    fn contains(self, position: Position) -> bool {
+        (0..self.width).contains(&position.x) && (0..self.height).contains(&position.y)
+    }
+
+    fn wrap(self, position: Position) -> Position {
+        Position::new(
+            position.x.rem_euclid(self.width),
+            position.y.rem_euclid(self.height),
+        )
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Robot {
+    position: Position,
+    velocity: Position,
+}
+
+impl Robot {
+    fn after(&self, seconds: i32, arena: Arena) -> Position {
+        arena.wrap(self.position + self.velocity * seconds)
+    }
+}
+
+#[derive(Debug)]
+pub struct Robots {
+    arena: Arena,
+    robots: Vec<Robot>,
+}
+
+impl Robots {
+    /// The same robots on `arena` instead of the one picked when parsing.
+    /// Fails, pointing at the robot's line, if a robot starts outside it.
+    fn with_arena(self, arena: Arena) -> ParseResult<Robots> {
+        if arena.width < 1 || arena.height < 1 {
+            return Err(ParseError::new(1, 1, "The arena needs at least one cell"));
+        }
+        if let Some(outside) = self
+            .robots
+            .iter()
+            .position(|robot| !arena.contains(robot.position))
+        {
+            return Err(ParseError::at_line(
+                outside,
+                format!(
+                    "Robot starts outside the {}x{} arena",
+                    arena.width, arena.height
+                ),
+            ));
+        }
+
+        Ok(Robots { arena, ..self })
+    }
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = Robots;
+
+    const SETTINGS: &'static [&'static str] = &["width", "height"];
+
+    fn parse(input: &str) -> ParseResult<Robots> {
+        parse_input(input)
+    }
+
+    /// `width` and `height` resize the arena, for inputs the guess made by
+    /// `parse` gets wrong.
+    fn configure(robots: Robots, settings: &Settings) -> ParseResult<Robots> {
+        let arena = Arena {
+            width: settings.get_or("width", robots.arena.width)?,
+            height: settings.get_or("height", robots.arena.height)?,
+        };
+        robots.with_arena(arena)
+    }
+
+    fn part1(robots: &Robots) -> Answer {
+        safety_factor(robots, SAFETY_SECONDS).into()
+    }
+
+    fn part2(robots: &Robots) -> Answer {
+        match picture_step(robots) {
+            Ok(step) => step.into(),
+            Err(reason) => Answer::NoSolution(reason),
+        }
+    }
+}
+
+fn parse_robot(line_index: usize, line: &str) -> ParseResult<Robot> {
+    if !line.starts_with("p=") || !line.contains(" v=") {
+        return Err(ParseError::at_line(
+            line_index,
+            "Expected a robot like p=0,4 v=3,-3",
+        ));
+    }
+
+    match parsing::integers(line_index, line)?[..] {
+        [x, y, dx, dy] => Ok(Robot {
+            position: Position::new(x, y),
+            velocity: Position::new(dx, dy),
+        }),
+        _ => Err(ParseError::at_line(
+            line_index,
+            "Expected two numbers for both p and v",
+        )),
+    }
+}
+
+/// Parses the robots onto a default arena, since the input does not say
+/// which one it is for: the example's when every robot starts inside it,
+/// the real one otherwise. The `width` and `height` settings pick another.
+fn parse_input(input: &str) -> ParseResult<Robots> {
+    let robots = parsing::lines(input)
+        .map(|(line_index, line)| parse_robot(line_index, line))
+        .collect::<ParseResult<Vec<Robot>>>()?;
+
+    let arena = if robots
+        .iter()
+        .all(|robot| EXAMPLE_ARENA.contains(robot.position))
+    {
+        EXAMPLE_ARENA
+    } else {
+        REAL_ARENA
+    };
+
+    Robots { arena, robots }.with_arena(arena)
+}
+
+/// The product of the robot counts in each quadrant after `seconds`. Robots
+/// on the middle row or column belong to no quadrant.
+fn safety_factor(robots: &Robots, seconds: i32) -> u64 {
+    let arena = robots.arena;
+    let (middle_x, middle_y) = (arena.width / 2, arena.height / 2);
+    let mut quadrants = [0u64; 4];
+
+    for robot in &robots.robots {
+        let position = robot.after(seconds, arena);
+        if position.x == middle_x || position.y == middle_y {
+            continue;
+        }
+
+        let index = usize::from(position.x > middle_x) + 2 * usize::from(position.y > middle_y);
+        quadrants[index] += 1;
+    }
+
+    quadrants.iter().product()
+}
+
+/// The first second at which the robots form a picture.
+///
+/// Each axis moves independently, so x positions repeat every `width`
+/// seconds and y positions every `height` seconds. The picture shows up as
+/// the moment each axis is most tightly bunched together. The real arena's
+/// sides are coprime, so the two moments meet exactly once per
+/// `width * height` seconds; on other arenas they may never meet.
+fn picture_step(robots: &Robots) -> Result<u32, String> {
+    let arena = robots.arena;
+    let x_step = tightest_step(robots, arena.width, |position| position.x);
+    let y_step = tightest_step(robots, arena.height, |position| position.y);
+
+    (0..arena.height)
+        .map(|cycle| x_step + cycle * arena.width)
+        .find(|step| step % arena.height == y_step)
+        .map(|step| step as u32)
+        .ok_or_else(|| {
+            format!(
+                "no second has both axes bunched together on a {}x{} arena",
+                arena.width, arena.height
+            )
+        })
+}
+
+/// The second within one `period` at which the robots' `axis` coordinates
+/// have the smallest variance.
+fn tightest_step(robots: &Robots, period: i32, axis: impl Fn(Position) -> i32) -> i32 {
+    let count = robots.robots.len() as i64;
+
+    (0..period)
+        .min_by_key(|&seconds| {
+            let (sum, sum_of_squares) =
+                robots.robots.iter().fold((0, 0), |(sum, squares), robot| {
+                    let value = axis(robot.after(seconds, robots.arena)) as i64;
+                    (sum + value, squares + value * value)
+                });
+            // The variance scaled by count², which keeps it an integer.
+            count * sum_of_squares - sum * sum
+        })
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_invalid_input() {
+        let error = Day14::parse("p=0,4 v=3,-3\np=6,3 v=-1").err().unwrap();
+        assert_eq!((error.line, error.column), (2, 1));
+
+        let error = Day14::parse("p=0,4 v=3,-3\np=101,3 v=1,1").err().unwrap();
+        assert_eq!((error.line, error.column), (2, 1));
+    }
+
+    #[test]
+    fn test_arena_settings() {
+        // One robot per corner, which only lands in four quadrants on the
+        // smaller arena.
+        let robots = Day14::parse("p=0,0 v=0,0\np=3,0 v=0,0\np=0,4 v=0,0\np=3,4 v=0,0").unwrap();
+        assert_eq!(robots.arena, EXAMPLE_ARENA);
+        assert_eq!(Day14::part1(&robots), 0);
+
+        let mut settings = Settings::default();
+        settings.set("width", 4);
+        settings.set("height", 5);
+        let robots = Day14::configure(robots, &settings).unwrap();
+        assert_eq!(safety_factor(&robots, 0), 1);
+
+        let robots = Day14::parse("p=4,0 v=1,1").unwrap();
+        let error = Day14::configure(robots, &settings).err().unwrap();
+        assert_eq!((error.line, error.column), (1, 1));
+
+        // Only the width is set, so the guessed height stays.
+        let mut settings = Settings::default();
+        settings.set("width", 101);
+        let robots = Day14::configure(Day14::parse("p=0,0 v=0,0").unwrap(), &settings).unwrap();
+        assert_eq!(
+            robots.arena,
+            Arena {
+                width: 101,
+                height: EXAMPLE_ARENA.height
+            }
+        );
+
+        settings.set("height", 0);
+        assert!(Day14::configure(robots, &settings).is_err());
+    }
+
+    #[test]
+    fn test_picture_step_without_common_second() {
+        // x is bunched at even seconds, y at odd ones, and a 2x2 arena has
+        // no second that is both.
+        let robots = Day14::parse("p=0,0 v=0,0\np=0,1 v=1,1")
+            .unwrap()
+            .with_arena(Arena {
+                width: 2,
+                height: 2,
+            })
+            .unwrap();
+        assert!(picture_step(&robots).is_err());
+        assert!(matches!(Day14::part2(&robots), Answer::NoSolution(_)));
+    }
+
+    #[test]
+    fn test_picture_step() {
+        const PICTURE_STEP: i32 = 1234;
+        let mut seed = 42u32;
+        let mut random = |limit: i32| {
+            seed = seed.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+            (seed >> 8) as i32 % limit
+        };
+
+        // Scattered robots, plus a 10x15 block that forms at PICTURE_STEP.
+        let mut robots = Vec::new();
+        for index in 0..500 {
+            let target = if index < 150 {
+                Position::new(40 + index % 10, 50 + index / 10)
+            } else {
+                Position::new(random(REAL_ARENA.width), random(REAL_ARENA.height))
+            };
+            let velocity = Position::new(random(201) - 100, random(201) - 100);
+            robots.push(Robot {
+                position: REAL_ARENA.wrap(target - velocity * PICTURE_STEP),
+                velocity,
+            });
+        }
+
+        let robots = Robots {
+            arena: REAL_ARENA,
+            robots,
+        };
+        assert_eq!(picture_step(&robots), Ok(PICTURE_STEP as u32));
+    }
+}
@@ -0,0 +1,309 @@
+use std::collections::HashSet;
+use std::fmt;
+
+use crate::error::{ParseError, ParseResult};
+use crate::grid::{Direction, Grid, Position};
+use crate::parsing;
+use crate::solution::{Answer, Solution};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Cell {
+    Empty,
+    Wall,
+    Box,
+    /// The left half of a box in the widened warehouse.
+    BoxLeft,
+    BoxRight,
+}
+
+impl fmt::Display for Cell {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let symbol = match self {
+            Cell::Empty => '.',
+            Cell::Wall => '#',
+            Cell::Box => 'O',
+            Cell::BoxLeft => '[',
+            Cell::BoxRight => ']',
+        };
+        write!(f, "{}", symbol)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Warehouse {
+    cells: Grid<Cell>,
+    robot: Position,
+}
+
+impl Warehouse {
+    /// The warehouse of part 2, where everything except the robot is twice
+    /// as wide.
+    fn widen(&self) -> Warehouse {
+        let mut cells = Grid::new(self.cells.width() * 2, self.cells.height(), Cell::Empty);
+
+        for (position, &cell) in self.cells.iter() {
+            let (left, right) = match cell {
+                Cell::Box => (Cell::BoxLeft, Cell::BoxRight),
+                _ => (cell, cell),
+            };
+            cells[Position::new(position.x * 2, position.y)] = left;
+            cells[Position::new(position.x * 2 + 1, position.y)] = right;
+        }
+
+        Warehouse {
+            cells,
+            robot: Position::new(self.robot.x * 2, self.robot.y),
+        }
+    }
+
+    /// Moves the robot one cell, pushing any boxes in the way. Nothing moves
+    /// if a wall blocks the robot or any of the boxes.
+    fn step(&mut self, direction: Direction) {
+        let vertical = matches!(direction, Direction::Up | Direction::Down);
+        let mut pushed = Vec::new();
+        let mut seen = HashSet::new();
+        let mut frontier = vec![self.robot];
+
+        // Collect every box cell the push reaches, nearest first.
+        while !frontier.is_empty() {
+            let mut next_frontier = Vec::new();
+
+            for position in frontier {
+                let next = position.step(direction);
+                let partner = match self.cells[next] {
+                    Cell::Wall => return,
+                    Cell::Empty => continue,
+                    Cell::Box => None,
+                    Cell::BoxLeft => Some(next.step(Direction::Right)),
+                    Cell::BoxRight => Some(next.step(Direction::Left)),
+                };
+
+                // Pushing a wide box sideways reaches its other half anyway.
+                let partner = partner.filter(|_| vertical);
+                for cell in [Some(next), partner].into_iter().flatten() {
+                    if seen.insert(cell) {
+                        pushed.push(cell);
+                        next_frontier.push(cell);
+                    }
+                }
+            }
+
+            frontier = next_frontier;
+        }
+
+        for &position in pushed.iter().rev() {
+            self.cells[position.step(direction)] = self.cells[position];
+            self.cells[position] = Cell::Empty;
+        }
+        self.robot = self.robot.step(direction);
+    }
+
+    /// The sum of every box's GPS coordinate, `100 * y + x` of its left edge.
+    fn gps_sum(&self) -> u64 {
+        self.cells
+            .iter()
+            .filter(|(_, &cell)| matches!(cell, Cell::Box | Cell::BoxLeft))
+            .map(|(position, _)| 100 * position.y as u64 + position.x as u64)
+            .sum()
+    }
+}
+
+impl fmt::Display for Warehouse {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for y in 0..self.cells.height() as i32 {
+            for x in 0..self.cells.width() as i32 {
+                let position = Position::new(x, y);
+                if position == self.robot {
+                    write!(f, "@")?;
+                } else {
+                    write!(f, "{}", self.cells[position])?;
+                }
+            }
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug)]
+pub struct Puzzle {
+    warehouse: Warehouse,
+    moves: Vec<Direction>,
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = Puzzle;
+
+    fn parse(input: &str) -> ParseResult<Puzzle> {
+        parse_input(input)
+    }
+
+    fn part1(puzzle: &Puzzle) -> Answer {
+        simulate(puzzle.warehouse.clone(), &puzzle.moves)
+            .gps_sum()
+            .into()
+    }
+
+    fn part2(puzzle: &Puzzle) -> Answer {
+        simulate(puzzle.warehouse.widen(), &puzzle.moves)
+            .gps_sum()
+            .into()
+    }
+}
+
+fn parse_input(input: &str) -> ParseResult<Puzzle> {
+    let blocks = parsing::blocks(input);
+    let Some((map, move_blocks)) = blocks.split_first() else {
+        return Err(ParseError::new(1, 1, "Expected a warehouse map"));
+    };
+
+    let map_text: Vec<&str> = map.iter().map(|(_, line)| *line).collect();
+    let symbols = Grid::parse(&map_text.join("\n"), |character| match character {
+        '.' | '#' | 'O' | '@' => Ok(character),
+        _ => Err("Invalid map cell, expected ., #, O or @"),
+    })
+    .map_err(|error| ParseError::new(error.line + map[0].0, error.column, error.message))?;
+
+    // Moves index the map without bounds checks, relying on the walls to
+    // stop the robot and the boxes before they reach the edge.
+    let (width, height) = (symbols.width() as i32, symbols.height() as i32);
+    if let Some((gap, _)) = symbols.iter().find(|&(position, &symbol)| {
+        let on_border = position.x == 0
+            || position.y == 0
+            || position.x == width - 1
+            || position.y == height - 1;
+        on_border && symbol != '#'
+    }) {
+        return Err(ParseError::new(
+            map[0].0 + gap.y as usize + 1,
+            gap.x as usize + 1,
+            "The warehouse must be surrounded by walls (#)",
+        ));
+    }
+
+    let robots: Vec<Position> = symbols
+        .iter()
+        .filter(|(_, &symbol)| symbol == '@')
+        .map(|(position, _)| position)
+        .collect();
+    let [robot] = robots[..] else {
+        return Err(ParseError::at_line(
+            map[0].0,
+            format!("Expected one robot (@) in the map, found {}", robots.len()),
+        ));
+    };
+
+    let cells = symbols.map(|&symbol| match symbol {
+        '#' => Cell::Wall,
+        'O' => Cell::Box,
+        _ => Cell::Empty,
+    });
+
+    let mut moves = Vec::new();
+    for &(line_index, line) in move_blocks.iter().flatten() {
+        for (x, character) in line.chars().enumerate() {
+            let direction = Direction::try_from(character)
+                .map_err(|message| ParseError::new(line_index + 1, x + 1, message))?;
+            moves.push(direction);
+        }
+    }
+
+    Ok(Puzzle {
+        warehouse: Warehouse { cells, robot },
+        moves,
+    })
+}
+
+/// Runs every move and returns the warehouse they leave behind.
+fn simulate(mut warehouse: Warehouse, moves: &[Direction]) -> Warehouse {
+    for &direction in moves {
+        warehouse.step(direction);
+    }
+
+    warehouse
+}
+
+/// The warehouse before and after every move, in the style of the puzzle's
+/// walkthrough. `cargo run --example warehouse -- [path] [--wide]` prints
+/// it for an input.
+pub fn render_steps(puzzle: &Puzzle, wide: bool) -> Result<String, String> {
+    let mut warehouse = if wide {
+        puzzle.warehouse.widen()
+    } else {
+        puzzle.warehouse.clone()
+    };
+    let mut rendered = format!("Initial state:\n{}", warehouse);
+
+    for &direction in &puzzle.moves {
+        let arrow = direction
+            .arrow()
+            .ok_or_else(|| format!("Cannot draw the move {:?} as an arrow", direction))?;
+        warehouse.step(direction);
+        rendered.push_str(&format!("\nMove {}:\n{}", arrow, warehouse));
+    }
+
+    Ok(rendered)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WIDE_EXAMPLE: &str = "#######
+#...#.#
+#.....#
+#..OO@#
+#..O..#
+#.....#
+#######
+
+<vv<<^^<<^^";
+
+    #[test]
+    fn test_render_steps() {
+        let puzzle = Day15::parse(WIDE_EXAMPLE).unwrap();
+        let rendered = render_steps(&puzzle, true).unwrap();
+
+        assert!(rendered.starts_with("Initial state:\n##############\n"));
+        assert!(rendered.contains(
+            "Move <:
+##############
+##......##..##
+##..........##
+##...[][]@..##
+##....[]....##
+##..........##
+##############
+"
+        ));
+        assert!(rendered.ends_with(
+            "Move ^:
+##############
+##...[].##..##
+##...@.[]...##
+##....[]....##
+##..........##
+##..........##
+##############
+"
+        ));
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        let error = Day15::parse("####\n#@x#\n####\n\n<>").err().unwrap();
+        assert_eq!((error.line, error.column), (2, 3));
+
+        let error = Day15::parse("####\n#@.#\n####\n\n<>\n^x").err().unwrap();
+        assert_eq!((error.line, error.column), (6, 2));
+
+        assert!(Day15::parse("####\n#..#\n####\n\n<>").is_err());
+
+        let error = Day15::parse("####\n#@..\n####\n\n>>>>").err().unwrap();
+        assert_eq!((error.line, error.column), (2, 4));
+        assert!(Day15::parse("@..\n\n>>>>").is_err());
+    }
+}
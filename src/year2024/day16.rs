@@ -0,0 +1,194 @@
+use std::collections::HashSet;
+
+use crate::error::{ParseError, ParseResult};
+use crate::grid::{Direction, Grid, Position};
+use crate::search::{self, SearchResult};
+use crate::solution::{Answer, Solution};
+
+const STEP_COST: u64 = 1;
+const TURN_COST: u64 = 1000;
+
+/// Where the reindeer is and which way it faces.
+type State = (Position, Direction);
+
+#[derive(Debug)]
+pub struct Maze {
+    walls: Grid<bool>,
+    start: Position,
+    end: Position,
+}
+
+impl Maze {
+    fn is_open(&self, position: Position) -> bool {
+        self.walls.get(position) == Some(&false)
+    }
+
+    /// The states reachable with one move, with the cost of that move.
+    fn moves(&self, (position, facing): State) -> Vec<(State, u64)> {
+        let mut moves = vec![
+            ((position, facing.turn_left()), TURN_COST),
+            ((position, facing.turn_right()), TURN_COST),
+        ];
+        let ahead = position.step(facing);
+        if self.is_open(ahead) {
+            moves.push(((ahead, facing), STEP_COST));
+        }
+
+        moves
+    }
+
+    /// The states that reach `state` with one move, with the cost of that
+    /// move.
+    fn reverse_moves(&self, (position, facing): State) -> Vec<(State, u64)> {
+        let mut moves = vec![
+            ((position, facing.turn_left()), TURN_COST),
+            ((position, facing.turn_right()), TURN_COST),
+        ];
+        let behind = position.step(facing.reverse());
+        if self.is_open(behind) {
+            moves.push(((behind, facing), STEP_COST));
+        }
+
+        moves
+    }
+
+    /// The lowest score of every state, starting from `start` facing east.
+    fn scores(&self) -> SearchResult<State> {
+        search::dijkstra([(self.start, Direction::Right)], |state| self.moves(state))
+    }
+
+    /// The end states reached with the lowest score, and that score.
+    fn best_ends(&self, scores: &SearchResult<State>) -> Option<(u64, Vec<State>)> {
+        let ends: Vec<(State, u64)> = Direction::CARDINAL
+            .iter()
+            .filter_map(|&facing| {
+                let state = (self.end, facing);
+                Some((state, scores.distance(state)?))
+            })
+            .collect();
+        let best = ends.iter().map(|&(_, score)| score).min()?;
+
+        Some((
+            best,
+            ends.into_iter()
+                .filter(|&(_, score)| score == best)
+                .map(|(state, _)| state)
+                .collect(),
+        ))
+    }
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input = Maze;
+
+    fn parse(input: &str) -> ParseResult<Maze> {
+        parse_input(input)
+    }
+
+    fn part1(maze: &Maze) -> Answer {
+        let (score, _) = maze
+            .best_ends(&maze.scores())
+            .expect("parsing checks that the end is reachable");
+        score.into()
+    }
+
+    fn part2(maze: &Maze) -> Answer {
+        count_best_path_tiles(maze).into()
+    }
+}
+
+fn parse_input(input: &str) -> ParseResult<Maze> {
+    let symbols = Grid::parse(input, |character| match character {
+        '#' | '.' | 'S' | 'E' => Ok(character),
+        _ => Err("Invalid maze cell, expected #, ., S or E"),
+    })?;
+
+    let find = |symbol: char| {
+        let found: Vec<Position> = symbols
+            .iter()
+            .filter(|&(_, &cell)| cell == symbol)
+            .map(|(position, _)| position)
+            .collect();
+        match found[..] {
+            [position] => Ok(position),
+            _ => Err(ParseError::new(
+                1,
+                1,
+                format!("Expected one {} in the maze, found {}", symbol, found.len()),
+            )),
+        }
+    };
+
+    let maze = Maze {
+        start: find('S')?,
+        end: find('E')?,
+        walls: symbols.map(|&cell| cell == '#'),
+    };
+
+    let reachable = search::bfs([maze.start], |position| {
+        maze.walls
+            .neighbors4(position)
+            .filter(|&neighbor| maze.is_open(neighbor))
+            .collect::<Vec<Position>>()
+    });
+    if !reachable.contains(maze.end) {
+        return Err(ParseError::new(
+            maze.end.y as usize + 1,
+            maze.end.x as usize + 1,
+            "The end cannot be reached from the start",
+        ));
+    }
+
+    Ok(maze)
+}
+
+/// Counts the tiles on any lowest-score path. Walking back from the best end
+/// states, a predecessor lies on an optimal path exactly when its own lowest
+/// score plus the move's cost equals the lowest score of the state it leads
+/// to, so every optimal predecessor is followed, not just the one the search
+/// happened to record.
+fn count_best_path_tiles(maze: &Maze) -> usize {
+    let scores = maze.scores();
+    let (_, ends) = maze
+        .best_ends(&scores)
+        .expect("parsing checks that the end is reachable");
+
+    let on_best_paths = search::bfs(ends, |state| {
+        let score = scores.distances[&state];
+        maze.reverse_moves(state)
+            .into_iter()
+            .filter(|&(previous, cost)| {
+                scores.distance(previous).map(|previous| previous + cost) == Some(score)
+            })
+            .map(|(previous, _)| previous)
+            .collect::<Vec<State>>()
+    });
+
+    on_best_paths
+        .order
+        .iter()
+        .map(|&(position, _)| position)
+        .collect::<HashSet<Position>>()
+        .len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_invalid_input() {
+        assert!(Day16::parse("####\n#SE#\n####").is_ok());
+
+        let error = Day16::parse("####\n#S.#\n####").err().unwrap();
+        assert!(error.message.contains("one E"));
+
+        let error = Day16::parse("#####\n#S.x#\n#####").err().unwrap();
+        assert_eq!((error.line, error.column), (2, 4));
+
+        let error = Day16::parse("#####\n#S#E#\n#####").err().unwrap();
+        assert_eq!((error.line, error.column), (2, 4));
+    }
+}
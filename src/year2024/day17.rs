@@ -0,0 +1,405 @@
+use std::fmt;
+
+use crate::error::{ParseError, ParseResult};
+use crate::parsing;
+use crate::solution::{Answer, Solution};
+
+/// How many instructions a run may execute before it counts as stuck in a
+/// loop. The puzzle's programs halt after a few hundred.
+const MAX_STEPS: usize = 1_000_000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Opcode {
+    Adv,
+    Bxl,
+    Bst,
+    Jnz,
+    Bxc,
+    Out,
+    Bdv,
+    Cdv,
+}
+
+impl Opcode {
+    fn from_bits(bits: u8) -> Option<Opcode> {
+        match bits {
+            0 => Some(Opcode::Adv),
+            1 => Some(Opcode::Bxl),
+            2 => Some(Opcode::Bst),
+            3 => Some(Opcode::Jnz),
+            4 => Some(Opcode::Bxc),
+            5 => Some(Opcode::Out),
+            6 => Some(Opcode::Bdv),
+            7 => Some(Opcode::Cdv),
+            _ => None,
+        }
+    }
+
+    /// Whether the operand is a combo operand rather than a literal.
+    fn takes_combo(self) -> bool {
+        matches!(
+            self,
+            Opcode::Adv | Opcode::Bst | Opcode::Out | Opcode::Bdv | Opcode::Cdv
+        )
+    }
+}
+
+impl fmt::Display for Opcode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Opcode::Adv => "adv",
+            Opcode::Bxl => "bxl",
+            Opcode::Bst => "bst",
+            Opcode::Jnz => "jnz",
+            Opcode::Bxc => "bxc",
+            Opcode::Out => "out",
+            Opcode::Bdv => "bdv",
+            Opcode::Cdv => "cdv",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// The register file and program of the 3-bit computer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Computer {
+    a: u64,
+    b: u64,
+    c: u64,
+    program: Vec<u8>,
+}
+
+impl Computer {
+    fn combo(&self, operand: u8) -> Result<u64, String> {
+        match operand {
+            0..=3 => Ok(operand as u64),
+            4 => Ok(self.a),
+            5 => Ok(self.b),
+            6 => Ok(self.c),
+            _ => Err(format!("combo operand {} is reserved", operand)),
+        }
+    }
+
+    /// A divided by 2 to the power of the combo operand. Shifting by 64 or
+    /// more leaves nothing, rather than overflowing the shift.
+    fn divide(&self, operand: u8) -> Result<u64, String> {
+        let shift = u32::try_from(self.combo(operand)?).unwrap_or(u32::MAX);
+        Ok(self.a.checked_shr(shift).unwrap_or(0))
+    }
+
+    /// Runs the program until the instruction pointer leaves it, returning
+    /// everything it printed. A jump can land on an odd address and read an
+    /// operand as an instruction, so the reserved combo operand is only
+    /// caught here, as is a program that never halts.
+    fn run(mut self) -> Result<Vec<u8>, String> {
+        let mut output = Vec::new();
+        let mut pointer = 0;
+
+        for _ in 0..MAX_STEPS {
+            let (Some(&bits), Some(&operand)) =
+                (self.program.get(pointer), self.program.get(pointer + 1))
+            else {
+                return Ok(output);
+            };
+            let opcode = Opcode::from_bits(bits)
+                .ok_or_else(|| format!("{} at {} is not a 3-bit opcode", bits, pointer))?;
+            pointer += 2;
+
+            match opcode {
+                Opcode::Adv => self.a = self.divide(operand)?,
+                Opcode::Bxl => self.b ^= operand as u64,
+                Opcode::Bst => self.b = self.combo(operand)? % 8,
+                Opcode::Jnz => {
+                    if self.a != 0 {
+                        pointer = operand as usize;
+                    }
+                }
+                Opcode::Bxc => self.b ^= self.c,
+                Opcode::Out => output.push((self.combo(operand)? % 8) as u8),
+                Opcode::Bdv => self.b = self.divide(operand)?,
+                Opcode::Cdv => self.c = self.divide(operand)?,
+            }
+        }
+
+        Err(format!(
+            "the program did not halt within {} steps",
+            MAX_STEPS
+        ))
+    }
+
+    fn run_with_a(&self, a: u64) -> Result<Vec<u8>, String> {
+        Computer { a, ..self.clone() }.run()
+    }
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input = Computer;
+
+    fn parse(input: &str) -> ParseResult<Computer> {
+        parse_input(input)
+    }
+
+    fn part1(computer: &Computer) -> Answer {
+        match computer.clone().run() {
+            Ok(output) => join_output(&output).into(),
+            Err(reason) => Answer::NoSolution(reason),
+        }
+    }
+
+    fn part2(computer: &Computer) -> Answer {
+        match find_quine(computer) {
+            Ok(Some(a)) => a.into(),
+            Ok(None) => Answer::NoSolution("no value of A makes the program print itself".into()),
+            Err(reason) => Answer::NoSolution(reason),
+        }
+    }
+}
+
+fn parse_register(lines: &[(usize, &str)], index: usize, name: &str) -> ParseResult<u64> {
+    let Some(&(line_index, line)) = lines.get(index) else {
+        let after = lines.last().map_or(0, |&(line_index, _)| line_index + 1);
+        return Err(ParseError::at_line(
+            after,
+            format!("Expected Register {}", name),
+        ));
+    };
+
+    let (key, value) = parsing::key_value(line_index, line, ":")?;
+    if key != format!("Register {}", name) {
+        return Err(ParseError::at_line(
+            line_index,
+            format!("Expected Register {}", name),
+        ));
+    }
+
+    parsing::number(line_index, line, value)
+}
+
+fn parse_input(input: &str) -> ParseResult<Computer> {
+    let blocks = parsing::blocks(input);
+    let (registers, program) = match &blocks[..] {
+        [registers, program] if program.len() == 1 => (registers, program[0]),
+        _ => {
+            return Err(ParseError::new(
+                1,
+                1,
+                "Expected three registers, a blank line and a program",
+            ))
+        }
+    };
+
+    let (line_index, line) = program;
+    let (key, value) = parsing::key_value(line_index, line, ":")?;
+    if key != "Program" {
+        return Err(ParseError::at_line(line_index, "Expected Program"));
+    }
+    let program = value
+        .split(',')
+        .map(|token| {
+            let token = token.trim();
+            match parsing::number(line_index, line, token)? {
+                bits @ 0..=7 => Ok(bits),
+                _ => Err(ParseError::at_token(
+                    line_index,
+                    line,
+                    token,
+                    "Expected a 3-bit number",
+                )),
+            }
+        })
+        .collect::<ParseResult<Vec<u8>>>()?;
+    if !program.len().is_multiple_of(2) {
+        return Err(ParseError::at_line(
+            line_index,
+            "Every instruction needs an operand",
+        ));
+    }
+    if program
+        .chunks(2)
+        .any(|pair| Opcode::from_bits(pair[0]).is_some_and(Opcode::takes_combo) && pair[1] == 7)
+    {
+        return Err(ParseError::at_line(
+            line_index,
+            "Combo operand 7 is reserved",
+        ));
+    }
+
+    Ok(Computer {
+        a: parse_register(registers, 0, "A")?,
+        b: parse_register(registers, 1, "B")?,
+        c: parse_register(registers, 2, "C")?,
+        program,
+    })
+}
+
+fn join_output(output: &[u8]) -> String {
+    output
+        .iter()
+        .map(u8::to_string)
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/// The lowest value of register A that makes the program print itself.
+///
+/// Programs like the puzzle's shift A right by three bits per loop and print
+/// a value derived from the low bits, so each output depends only on A's
+/// top bits so far. Building A three bits at a time, matching the program
+/// from its last value backwards, keeps the search tiny.
+fn find_quine(computer: &Computer) -> Result<Option<u64>, String> {
+    let mut candidates = vec![0u64];
+
+    for start in (0..computer.program.len()).rev() {
+        let expected = &computer.program[start..];
+        let mut matching = Vec::new();
+        for a in candidates {
+            for bits in 0..8 {
+                let a = a << 3 | bits;
+                if computer.run_with_a(a)? == expected {
+                    matching.push(a);
+                }
+            }
+        }
+        candidates = matching;
+    }
+
+    Ok(candidates.into_iter().filter(|&a| a > 0).min())
+}
+
+/// The computer's program as one instruction per line, with what each one
+/// does. `cargo run --example disassemble -- <input>` prints it for a puzzle
+/// input.
+pub fn disassemble(computer: &Computer) -> String {
+    let combo = |operand: u8| match operand {
+        0..=3 => operand.to_string(),
+        4 => "A".to_string(),
+        5 => "B".to_string(),
+        6 => "C".to_string(),
+        _ => "?".to_string(),
+    };
+
+    computer
+        .program
+        .chunks(2)
+        .enumerate()
+        .map(|(index, pair)| {
+            let (bits, operand) = (pair[0], pair[1]);
+            let Some(opcode) = Opcode::from_bits(bits) else {
+                return format!("{:>3}: {} {}  not an opcode\n", index * 2, bits, operand);
+            };
+            let shown = if opcode.takes_combo() {
+                combo(operand)
+            } else {
+                operand.to_string()
+            };
+            let effect = match opcode {
+                Opcode::Adv => format!("A = A >> {}", shown),
+                Opcode::Bxl => format!("B = B ^ {}", shown),
+                Opcode::Bst => format!("B = {} % 8", shown),
+                Opcode::Jnz => format!("if A != 0 jump to {}", shown),
+                Opcode::Bxc => "B = B ^ C".to_string(),
+                Opcode::Out => format!("print {} % 8", shown),
+                Opcode::Bdv => format!("B = A >> {}", shown),
+                Opcode::Cdv => format!("C = A >> {}", shown),
+            };
+            format!("{:>3}: {} {}  {}\n", index * 2, opcode, shown, effect)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn computer(a: u64, b: u64, c: u64, program: &[u8]) -> Computer {
+        Computer {
+            a,
+            b,
+            c,
+            program: program.to_vec(),
+        }
+    }
+
+    #[test]
+    fn test_instructions() {
+        // The walkthrough's register checks, with `out B` appended.
+        assert_eq!(computer(0, 0, 9, &[2, 6, 5, 5]).run().unwrap(), [1]);
+        assert_eq!(computer(0, 29, 0, &[1, 7, 5, 5]).run().unwrap(), [26 % 8]);
+        assert_eq!(
+            computer(0, 2024, 43690, &[4, 0, 5, 5]).run().unwrap(),
+            [(44354 % 8) as u8]
+        );
+
+        assert_eq!(
+            computer(10, 0, 0, &[5, 0, 5, 1, 5, 4]).run().unwrap(),
+            [0, 1, 2]
+        );
+        assert_eq!(
+            computer(2024, 0, 0, &[0, 1, 5, 4, 3, 0]).run().unwrap(),
+            [4, 2, 5, 6, 7, 7, 7, 7, 3, 1, 0]
+        );
+    }
+
+    #[test]
+    fn test_wide_shifts() {
+        // A / 2^64 is 0, which a plain `>>` would overflow on.
+        assert_eq!(computer(8, 64, 0, &[0, 5, 5, 4]).run().unwrap(), [0]);
+        assert_eq!(computer(8, 64, 0, &[6, 5, 5, 5]).run().unwrap(), [0]);
+        assert_eq!(computer(8, 0, 1 << 40, &[7, 6, 5, 6]).run().unwrap(), [0]);
+    }
+
+    #[test]
+    fn test_runs_that_fail() {
+        // Jumping to 3 reads `out 7`, which parsing's even-address check
+        // never saw.
+        let computer =
+            Day17::parse("Register A: 1\nRegister B: 0\nRegister C: 0\n\nProgram: 3,3,1,5,7,1")
+                .unwrap();
+        assert!(computer.clone().run().unwrap_err().contains("reserved"));
+        assert!(matches!(Day17::part1(&computer), Answer::NoSolution(_)));
+        assert!(matches!(Day17::part2(&computer), Answer::NoSolution(_)));
+
+        let computer =
+            Day17::parse("Register A: 1\nRegister B: 0\nRegister C: 0\n\nProgram: 3,0").unwrap();
+        assert!(computer.clone().run().unwrap_err().contains("halt"));
+        assert!(matches!(Day17::part1(&computer), Answer::NoSolution(_)));
+    }
+
+    #[test]
+    fn test_no_quine() {
+        // Prints a single value and halts, so it never prints all four.
+        let computer =
+            Day17::parse("Register A: 0\nRegister B: 0\nRegister C: 0\n\nProgram: 1,0,5,4")
+                .unwrap();
+        assert_eq!(find_quine(&computer), Ok(None));
+        assert!(matches!(Day17::part2(&computer), Answer::NoSolution(_)));
+    }
+
+    #[test]
+    fn test_disassemble() {
+        assert_eq!(
+            disassemble(&computer(0, 0, 0, &[0, 3, 5, 4, 3, 0])),
+            "  0: adv 3  A = A >> 3\n  2: out A  print A % 8\n  4: jnz 0  if A != 0 jump to 0\n"
+        );
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        let error = Day17::parse("Register A: 1\nRegister B: 0\nRegister C: 0\n\nProgram: 0,9")
+            .err()
+            .unwrap();
+        assert_eq!((error.line, error.column), (5, 12));
+
+        let error = Day17::parse("Register A: 1\nRegister X: 0\nRegister C: 0\n\nProgram: 0,1")
+            .err()
+            .unwrap();
+        assert_eq!(error.line, 2);
+
+        let error = Day17::parse("Register A: 1\nRegister B: 0\nRegister C: 0\n\nProgram: 0,7")
+            .err()
+            .unwrap();
+        assert_eq!(error.line, 5);
+    }
+}
@@ -0,0 +1,243 @@
+use crate::error::{ParseError, ParseResult};
+use crate::grid::{Grid, Position};
+use crate::parsing;
+use crate::search;
+use crate::solution::{Answer, Settings, Solution};
+
+/// The walkthrough's 7x7 grid, where part 1 looks at the first 12 bytes.
+const EXAMPLE_SPACE: Space = Space {
+    size: 7,
+    fallen: 12,
+};
+/// The grid real inputs fall onto, where part 1 looks at the first kilobyte.
+const REAL_SPACE: Space = Space {
+    size: 71,
+    fallen: 1024,
+};
+
+/// The widest space the `size` setting accepts. Every cell is stored, so
+/// this keeps a mistyped size from allocating gigabytes.
+const MAX_SIZE: i32 = 1024;
+
+/// A square memory space and how many bytes have fallen at part 1.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Space {
+    size: i32,
+    fallen: usize,
+}
+
+impl Space {
+    fn contains(self, position: Position) -> bool {
+        (0..self.size).contains(&position.x) && (0..self.size).contains(&position.y)
+    }
+}
+
+#[derive(Debug)]
+pub struct Memory {
+    space: Space,
+    bytes: Vec<Position>,
+    /// For every cell, the index of the byte that corrupts it, or
+    /// `usize::MAX` if none does.
+    corrupted_at: Grid<usize>,
+}
+
+impl Memory {
+    /// Drops `bytes` onto `space`, failing at the first byte outside it.
+    fn new(bytes: Vec<Position>, space: Space) -> ParseResult<Memory> {
+        if !(1..=MAX_SIZE).contains(&space.size) {
+            return Err(ParseError::new(
+                1,
+                1,
+                format!("The memory space must be 1 to {} cells wide", MAX_SIZE),
+            ));
+        }
+
+        let size = space.size as usize;
+        let mut corrupted_at = Grid::new(size, size, usize::MAX);
+        for (index, &byte) in bytes.iter().enumerate() {
+            if !space.contains(byte) {
+                return Err(ParseError::at_line(
+                    index,
+                    format!(
+                        "Byte falls outside the {}x{} memory space",
+                        space.size, space.size
+                    ),
+                ));
+            }
+            // A byte landing on an already corrupted cell changes nothing.
+            let cell = &mut corrupted_at[byte];
+            *cell = (*cell).min(index);
+        }
+
+        Ok(Memory {
+            space,
+            bytes,
+            corrupted_at,
+        })
+    }
+
+    /// The fewest steps from the top left to the bottom right corner once
+    /// `fallen` bytes have fallen, or `None` if the exit is cut off.
+    fn shortest_path(&self, fallen: usize) -> Option<u64> {
+        let start = Position::new(0, 0);
+        let exit = Position::new(self.space.size - 1, self.space.size - 1);
+        let is_open = |position: Position| self.corrupted_at[position] >= fallen;
+        if !is_open(start) {
+            return None;
+        }
+
+        search::bfs([start], |position| {
+            self.corrupted_at
+                .neighbors4(position)
+                .filter(|&neighbor| is_open(neighbor))
+                .collect::<Vec<Position>>()
+        })
+        .distance(exit)
+    }
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    type Input = Memory;
+
+    const SETTINGS: &'static [&'static str] = &["size", "fallen"];
+
+    fn parse(input: &str) -> ParseResult<Memory> {
+        parse_input(input)
+    }
+
+    /// `size` is the side of the square space and `fallen` how many bytes
+    /// part 1 waits for. Either one left out keeps the value `parse` chose.
+    fn configure(memory: Memory, settings: &Settings) -> ParseResult<Memory> {
+        let space = Space {
+            size: settings.get_or("size", memory.space.size)?,
+            fallen: settings.get_or("fallen", memory.space.fallen)?,
+        };
+        Memory::new(memory.bytes, space)
+    }
+
+    fn part1(memory: &Memory) -> Answer {
+        let fallen = memory.space.fallen;
+        match memory.shortest_path(fallen) {
+            Some(steps) => steps.into(),
+            None => Answer::NoSolution(format!("the first {} bytes cut the exit off", fallen)),
+        }
+    }
+
+    fn part2(memory: &Memory) -> Answer {
+        match first_blocking_byte(memory) {
+            Some(byte) => format!("{},{}", byte.x, byte.y).into(),
+            None => Answer::NoSolution(format!(
+                "the exit stays reachable after all {} bytes",
+                memory.bytes.len()
+            )),
+        }
+    }
+}
+
+fn parse_byte(line_index: usize, line: &str) -> ParseResult<Position> {
+    match parsing::separated(line_index, line, ',')?[..] {
+        [x, y] => Ok(Position::new(x, y)),
+        _ => Err(ParseError::at_line(
+            line_index,
+            "Expected a byte position like 6,1",
+        )),
+    }
+}
+
+/// Reads one `x,y` byte per line. Nothing in the file gives the grid's
+/// size, so bytes that all fit in 7x7 get the walkthrough's numbers and
+/// any other list the real 71x71 grid with 1024 bytes for part 1.
+fn parse_input(input: &str) -> ParseResult<Memory> {
+    let bytes = parsing::lines(input)
+        .map(|(line_index, line)| parse_byte(line_index, line))
+        .collect::<ParseResult<Vec<Position>>>()?;
+
+    let space = if bytes.iter().all(|&byte| EXAMPLE_SPACE.contains(byte)) {
+        EXAMPLE_SPACE
+    } else {
+        REAL_SPACE
+    };
+
+    Memory::new(bytes, space)
+}
+
+/// The first byte after which the exit can no longer be reached.
+///
+/// Bytes only ever add walls, so once the exit is cut off it stays cut off.
+/// That makes reachability monotonic in the number of fallen bytes, and a
+/// binary search needs only a logarithmic number of searches.
+fn first_blocking_byte(memory: &Memory) -> Option<Position> {
+    // The exit is reachable after `low` bytes and cut off after `high`.
+    let (mut low, mut high) = (0, memory.bytes.len());
+    memory.shortest_path(low)?;
+    if memory.shortest_path(high).is_some() {
+        return None;
+    }
+
+    while high - low > 1 {
+        let middle = (low + high) / 2;
+        if memory.shortest_path(middle).is_some() {
+            low = middle;
+        } else {
+            high = middle;
+        }
+    }
+
+    Some(memory.bytes[high - 1])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_invalid_input() {
+        let error = Day18::parse("1,2\n3").err().unwrap();
+        assert_eq!((error.line, error.column), (2, 1));
+
+        let error = Day18::parse("1,2\n3,x").err().unwrap();
+        assert_eq!((error.line, error.column), (2, 3));
+
+        let error = Day18::parse("1,2\n71,0").err().unwrap();
+        assert_eq!((error.line, error.column), (2, 1));
+    }
+
+    #[test]
+    fn test_space_settings() {
+        // A wall across the second row, with a gap at the far end until the
+        // fourth byte closes it.
+        let memory = Day18::parse("0,1\n1,1\n2,1\n3,1").unwrap();
+        assert_eq!(memory.space, EXAMPLE_SPACE);
+
+        let mut settings = Settings::default();
+        settings.set("size", 4);
+        settings.set("fallen", 3);
+        let memory = Day18::configure(memory, &settings).unwrap();
+        assert_eq!(Day18::part1(&memory), Answer::from(6u64));
+        assert_eq!(Day18::part2(&memory), Answer::from("3,1"));
+
+        // Only the count is set, so the guessed size stays.
+        let mut settings = Settings::default();
+        settings.set("fallen", 4);
+        let memory = Day18::configure(memory, &settings).unwrap();
+        assert_eq!(memory.space, Space { size: 4, fallen: 4 });
+        assert!(matches!(Day18::part1(&memory), Answer::NoSolution(_)));
+
+        settings.set("size", 3);
+        let error = Day18::configure(memory, &settings).err().unwrap();
+        assert_eq!((error.line, error.column), (4, 1));
+
+        let memory = Day18::parse("0,1").unwrap();
+        settings.set("size", MAX_SIZE as u64 + 1);
+        assert!(Day18::configure(memory, &settings).is_err());
+    }
+
+    #[test]
+    fn test_never_blocked() {
+        let memory = Day18::parse("1,1\n2,2").unwrap();
+        assert_eq!(memory.shortest_path(2), Some(12));
+        assert!(matches!(Day18::part2(&memory), Answer::NoSolution(_)));
+    }
+}
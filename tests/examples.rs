@@ -0,0 +1,88 @@
+//! Runs every example input in each year's `inputs/<year>/examples` through
+//! the registered solutions and compares the answers recorded in its
+//! `answers.toml`. Each
+//! example is also run with the line endings and trailing blank lines that
+//! editors on other platforms tend to produce.
+
+use std::path::Path;
+
+use advent_of_code_2024::answers::Outcome;
+use advent_of_code_2024::{examples, registry};
+
+fn variants(input: &str) -> [(&'static str, String); 4] {
+    [
+        ("as written", input.to_string()),
+        ("CRLF", input.replace("\r\n", "\n").replace('\n', "\r\n")),
+        ("no final newline", input.trim_end().to_string()),
+        (
+            "trailing blank lines",
+            format!("{}\n\n  \n", input.trim_end()),
+        ),
+    ]
+}
+
+#[test]
+fn examples_match_recorded_answers() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let mut failures = Vec::new();
+    let mut checked = 0;
+
+    for year in registry::years() {
+        let dir = root.join(examples::dir_for(year));
+        let examples = examples::discover(&dir).unwrap();
+
+        for example in &examples {
+            let Some(registered) = registry::find(year, example.day) else {
+                failures.push(format!(
+                    "{} {}: day {} is not registered",
+                    year,
+                    example.name(),
+                    example.day
+                ));
+                continue;
+            };
+            let input = example.read_input().unwrap();
+
+            for (variant, input) in variants(&input) {
+                match examples::check(example, registered.solution, &input) {
+                    Ok(outcomes) => {
+                        for (part, outcome) in outcomes {
+                            checked += 1;
+                            if let Outcome::Mismatched { expected, actual } = outcome {
+                                failures.push(format!(
+                                    "{} {} ({}) part {}: expected {}, got {}",
+                                    year,
+                                    example.name(),
+                                    variant,
+                                    part,
+                                    expected,
+                                    actual
+                                ));
+                            }
+                        }
+                    }
+                    Err(err) => failures.push(format!(
+                        "{} {} ({}): {}",
+                        year,
+                        example.name(),
+                        variant,
+                        err
+                    )),
+                }
+            }
+        }
+    }
+
+    assert!(
+        failures.is_empty(),
+        "{} of {} example answers failed:\n{}",
+        failures.len(),
+        checked,
+        failures.join("\n")
+    );
+    assert!(
+        checked > 0,
+        "No example answers found in {}",
+        root.display()
+    );
+}